@@ -1,12 +1,28 @@
 use super::*;
 
 pub const MAX_BOND_MAPPINGS: usize = 6;
+pub const MAX_TRACKED_PEERS: usize = 8;    // Peers with their own bridge counters in SovereignCoinStats
+pub const INTEREST_RATE_HISTORY_LEN: usize = 16; // Past crank updates kept for auditors
+pub const MIN_INTEREST_RATE_UPDATE_INTERVAL: i64 = 3600; // Keepers can crank at most hourly
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+pub const INTEREST_BEARING_SECONDS_PER_YEAR: i128 = 31_556_736; // Token-2022 compounds over 365.24 day years
+pub const MAX_DYNAMIC_FEE_POINTS: usize = 8; // Breakpoints in the reserve health fee curve
+pub const MAX_FX_SPREAD_BPS: u16 = 500;      // Issuers can widen the mint/redeem rate by at most 5%
+pub const MAX_RFQ_ORACLE_DEVIATION_BPS: u16 = 500; // Issuers can quote at most 5% away from the oracle price
+pub const MAX_PRICE_ROUTE_LEGS: usize = 4;   // Feeds a cross-rate route can chain, e.g. USDC→USD→EUR→CHF
+pub const PRICE_HISTORY_LEN: usize = 64;     // Oracle prices kept per coin for TWAP and audits
+pub const MIN_PRICE_HISTORY_CRANK_INTERVAL: i64 = 60; // Keepers can sample the oracle at most once a minute
+pub const DEFAULT_PRICE_HISTORY_TWAP_WINDOW: i64 = 3600; // TWAP window a new coin starts with, the deviation check starts off
+pub const REWARD_INDEX_PRECISION: u128 = 1_000_000_000_000_000_000; // 1e18 scale for the claimable reward index
 
 pub const PRICE_PRECISION: u64 = 1_000_000_000; // 1e9 precision for prices
+pub const PRICE_PRECISION_DECIMALS: u8 = 9;       // Decimal places of PRICE_PRECISION
+pub const ORACLE_PRICE_SCALE: u32 = 18;          // Decimal places oracle prices are normalised to
 pub const BASIS_POINT_MAX: u16 = 10000;       // 100% in basis points        
 pub const SCALE_OFFSET: u32 = 64;
 pub const ONE_Q64: u128 = 1u128 << SCALE_OFFSET; // 1.0 in Q64.64 fixed-point
 pub const USDC_MINT: Pubkey = pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+pub const USDC_DECIMALS: u8 = 6;
 pub const ETHERFUSE_FEE_COLLECTOR: Pubkey = pubkey!("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM");
 
 // LayerZero seeds
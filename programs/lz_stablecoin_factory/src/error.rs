@@ -113,6 +113,110 @@ pub enum StablecoinError {
     DivisionByZero,
     #[msg("Invalid USDC Reserve")]
     InvalidUSDCReserve,
+    #[msg("Invalid metadata account")]
+    InvalidMetadataAccount,
+    #[msg("No metadata fields were provided to update")]
+    EmptyMetadataUpdate,
+    #[msg("This operation requires a Token-2022 metadata sovereign coin")]
+    TokenMetadataRequired,
+    #[msg("Sovereign coin is not active")]
+    SovereignCoinNotActive,
+    #[msg("Sovereign coin no longer accepts redemptions through the reserve")]
+    RedemptionsClosed,
+    #[msg("Invalid sovereign coin lifecycle transition")]
+    InvalidLifecycleTransition,
+    #[msg("Sovereign coin has not been settled")]
+    SovereignCoinNotSettled,
+    #[msg("Sovereign coin still has outstanding supply")]
+    OutstandingSupply,
+    #[msg("Bond accounts must be empty before closing")]
+    BondAccountsNotEmpty,
+    #[msg("Interest rate floor must not exceed the ceiling")]
+    InvalidInterestRateBounds,
+    #[msg("Interest rate update interval is too short")]
+    InvalidInterestRateInterval,
+    #[msg("Interest rate was updated too recently")]
+    InterestRateUpdateTooSoon,
+    #[msg("Basket weights must be set for 1 to 6 bonds and sum to 100%")]
+    InvalidBasketWeights,
+    #[msg("Basket constituent accounts are missing or out of order")]
+    InvalidBasketAccounts,
+    #[msg("Bond basket account required for basket sovereign coins")]
+    BondBasketRequired,
+    #[msg("Operation is not supported for basket sovereign coins")]
+    BasketNotSupported,
+    #[msg("Bond basket can only be set before the first mint")]
+    BasketAlreadyInUse,
+    #[msg("Fee curve thresholds, fees or slope are invalid")]
+    InvalidFeeCurve,
+    #[msg("Tax withholding exceeds 100%")]
+    InvalidTaxWithholding,
+    #[msg("Maximum number of per-currency tax rates reached")]
+    MaxCurrencyTaxRatesReached,
+    #[msg("No tax rate override exists for this currency")]
+    CurrencyTaxRateNotFound,
+    #[msg("No protocol, issuer or holder yield has accrued")]
+    NoYieldToSettle,
+    #[msg("Operation is not available in this coin's yield mode")]
+    YieldModeMismatch,
+    #[msg("Coin is already in this yield mode or cannot use it")]
+    InvalidYieldModeMigration,
+    #[msg("Withdrawal exceeds the deposited amount")]
+    InsufficientRewardDeposit,
+    #[msg("No rewards to claim")]
+    NoRewardsToClaim,
+    #[msg("Account for the coin's reward payout is missing")]
+    RewardPayoutAccountRequired,
+    #[msg("Coin has no backing to mint rewards against")]
+    NoBackingForRewardMint,
+    #[msg("Reserve policy floor, cap, steps or concentration settings are invalid")]
+    InvalidReservePolicy,
+    #[msg("Marked collateral ratio is below the policy minimum")]
+    CollateralRatioTooLow,
+    #[msg("Dynamic fee curve breakpoints must be ascending with fees below 100%")]
+    InvalidDynamicFeeCurve,
+    #[msg("FX spread exceeds the maximum allowed")]
+    InvalidFxSpread,
+    #[msg("No spread revenue has accrued")]
+    NoSpreadRevenue,
+    #[msg("USDC is trading outside its peg band, USD coin mints and redemptions are paused")]
+    UsdcDepegged,
+    #[msg("Peg band must be greater than zero and below 100%")]
+    InvalidPegBand,
+    #[msg("Price route must chain from USDC to the route currency")]
+    InvalidPriceRoute,
+    #[msg("Price feeds do not match the configured route")]
+    PriceRouteFeedMismatch,
+    #[msg("RFQ quote does not match this trade")]
+    InvalidRfqQuote,
+    #[msg("RFQ quote has expired")]
+    RfqQuoteExpired,
+    #[msg("RFQ quote is not signed by the coin's quote signer")]
+    InvalidRfqSignature,
+    #[msg("RFQ quotes are disabled for this coin")]
+    RfqQuotesDisabled,
+    #[msg("RFQ quote is too far from the oracle price")]
+    RfqQuoteOffMarket,
+    #[msg("Invalid RFQ signer configuration")]
+    InvalidRfqSignerConfig,
+    #[msg("Oracle price deviates too far from the TWAP")]
+    PriceDeviatesFromTwap,
+    #[msg("Price history was sampled too recently")]
+    PriceHistoryUpdateTooSoon,
+    #[msg("Invalid price history configuration")]
+    InvalidPriceHistoryConfig,
+    #[msg("USDC top-up does not cover the fee, spread and reserve share of the mint")]
+    ReserveTopUpTooLow,
+    #[msg("Minted amount is below the requested minimum")]
+    MintAmountBelowMinimum,
+    #[msg("In-kind bond redemptions are disabled for this coin")]
+    InKindRedemptionDisabled,
+    #[msg("In-kind bond redemption is outside the coin's limits")]
+    InKindRedemptionLimitExceeded,
+    #[msg("Invalid in-kind redemption limits")]
+    InvalidInKindRedemptionLimits,
+    #[msg("Account is already in the current layout")]
+    AccountAlreadyMigrated,
     // LayerZero specific errors
     #[msg("Unauthorized LayerZero operation")]
     LzUnauthorized = 7000,
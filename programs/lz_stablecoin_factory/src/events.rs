@@ -90,6 +90,32 @@ pub struct SovereignCoinMintedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SovereignCoinMintedWithBondsEvent {
+    pub payer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub bond_amount: u64,      // Raw bond tokens received into the holding
+    pub bond_value: u64,       // Their USDC value at the oracle price
+    pub usdc_amount: u64,      // USDC top-up
+    pub sovereign_coin_amount: u64,
+    pub reserve_amount: u64,
+    pub protocol_fee: u64,
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinMetadataUpdatedEvent {
+    pub issuer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub mint: Pubkey,
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub image: Option<String>,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SovereignCoinInterestBearingWithTransferFeeInitializedEvent {
     pub sovereign_coin: Pubkey,
@@ -123,6 +149,53 @@ pub struct SovereignCoinRedeemedEvent {
     pub redemption_type: RedemptionTypeState,
 }
 
+#[event]
+pub struct SovereignCoinLifecycleChangedEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub old_lifecycle: SovereignCoinLifecycle,
+    pub new_lifecycle: SovereignCoinLifecycle,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinSettledEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub bonds_redeemed: u64,
+    pub holder_usdc_received: u64,
+    pub protocol_bonds_redeemed: u64,
+    pub protocol_usdc_received: u64,
+    pub protocol_yield_paid: u64, // Yield claims paid out of the holder bond proceeds
+    pub issuer_yield_paid: u64,
+    pub holder_yield_paid: u64,   // Left in the reserve as claimable rewards
+    pub settlement_usdc_amount: u64,
+    pub outstanding_supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinSettlementClaimedEvent {
+    pub holder: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub sovereign_amount: u64,
+    pub usdc_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinClosedEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub rent_receiver: Pubkey,
+    pub remaining_sovereign_coins: u64,
+    pub protocol_supply_burned: u64, // Protocol owned coins burned on close
+    pub unclaimed_usdc: u64,         // Settlement rounding left after every coin was claimed, swept to the protocol vault
+    pub reward_usdc_swept: u64,      // Rewards nobody claimed before the last coin was, swept to the protocol vault
+    pub spread_revenue_paid: u64,    // Spread revenue paid to the coin's spread recipient
+    pub timestamp: i64,
+}
+
 /// Create claim fee operator
 #[event]
 pub struct EvtCreateClaimFeeOperator {
@@ -146,6 +219,57 @@ pub struct SovereignCoinInterestRateUpdatedEvent {
     pub timestamp: i64, 
 }
 
+#[event]
+pub struct InterestRateConfigUpdatedEvent {
+    pub sovereign_coin: Pubkey,
+    pub admin: Pubkey,
+    pub update_interval: i64,
+    pub max_rate_step_bps: u16,
+    pub rate_floor: i16,
+    pub rate_ceiling: i16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InterestRateCrankedEvent {
+    pub sovereign_coin: Pubkey,
+    pub keeper: Pubkey,
+    pub previous_rate: i16,
+    pub target_rate: i16,
+    pub new_rate: i16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeCurveUpdatedEvent {
+    pub admin: Pubkey,
+    pub low_yield_threshold_bps: u16,
+    pub high_yield_threshold_bps: u16,
+    pub low_fee_bps: u16,
+    pub high_fee_bps: u16,
+    pub slope: u32,
+    pub intercept: u32,
+    pub tax_withholding_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurrencyTaxRateUpdatedEvent {
+    pub admin: Pubkey,
+    pub fiat_currency: String,
+    pub tax_withholding_bps: Option<u16>,  // None when the override was removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BondBasketConfiguredEvent {
+    pub sovereign_coin: Pubkey,
+    pub creator: Pubkey,
+    pub bond_mints: Vec<Pubkey>,
+    pub weights_bps: Vec<u16>,
+    pub timestamp: i64,
+}
+
 // Event emitted when a sovereign coin with interest-bearing properties is initialized.
 #[event]
 pub struct SovereignCoinInterestBearingInitializedEvent {
@@ -240,4 +364,234 @@ pub struct LzEmergencyStopEvent {
     pub oft_store: Pubkey,
     pub emergency_admin: Pubkey,
     pub timestamp: i64,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct YieldClaimsSettledEvent {
+    pub sovereign_coin: Pubkey,
+    pub keeper: Pubkey,
+    pub protocol_claim: u64,   // Accrued claims, in USDC
+    pub issuer_claim: u64,
+    pub holder_claim: u64,     // Claimable coins only
+    pub protocol_paid: u64,    // What the bond redemption actually paid out
+    pub issuer_paid: u64,
+    pub holder_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardDepositChangedEvent {
+    pub sovereign_coin: Pubkey,
+    pub holder: Pubkey,
+    pub deposited: i64,        // Positive for deposits, negative for withdrawals
+    pub holder_deposit: u64,
+    pub total_deposits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardsClaimedEvent {
+    pub sovereign_coin: Pubkey,
+    pub holder: Pubkey,
+    pub payout: RewardPayout,
+    pub usdc_amount: u64,      // Rewards claimed, in USDC
+    pub sovereign_amount: u64, // Coins minted for them, zero for USDC payouts
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct YieldModeMigratedEvent {
+    pub sovereign_coin: Pubkey,
+    pub admin: Pubkey,
+    pub old_yield_mode: YieldMode,
+    pub new_yield_mode: YieldMode,
+    pub reward_payout: RewardPayout,
+    pub timestamp: i64,
+}
+
+/// A bridge with a peer beyond the first MAX_TRACKED_PEERS of a coin. It is counted in the
+/// SovereignCoinStats totals but the peer has no per-peer counters, this event is its only record.
+#[event]
+pub struct PeerStatsUntrackedEvent {
+    pub sovereign_coin: Pubkey,
+    pub eid: u32,              // LayerZero endpoint id of the peer chain
+    pub is_inbound: bool,
+    pub amount: u64,
+    pub max_tracked_peers: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FactoryMigratedEvent {
+    pub factory: Pubkey,
+    pub admin: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinMigratedEvent {
+    pub sovereign_coin: Pubkey,
+    pub admin: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+    pub yield_mode: YieldMode, // Picked from whether the coin was interest-bearing before the upgrade
+    pub fiat_currency: [u8; 8],
+    pub total_supply: u64,     // Supply and backing counted into the currency and factory totals
+    pub usdc_amount: u64,
+    pub bond_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservePolicyUpdatedEvent {
+    pub admin: Pubkey,
+    pub curve_kind: ReserveCurveKind,
+    pub rating_steps_bps: [u16; 10],
+    pub convex_coefficient_bps: u16,
+    pub floor_bps: u16,
+    pub cap_bps: u16,
+    pub concentration_threshold_bps: u16,
+    pub concentration_surcharge_bps: u16,
+    pub min_mint_collateral_ratio_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReserveRequirementRefreshedEvent {
+    pub sovereign_coin: Pubkey,
+    pub old_required_reserve_percentage: u16,
+    pub new_required_reserve_percentage: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DynamicFeesUpdatedEvent {
+    pub admin: Pubkey,
+    pub enabled: bool,
+    pub points: Vec<DynamicFeePoint>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceRouteUpdatedEvent {
+    pub admin: Pubkey,
+    pub fiat_currency: String,
+    pub legs: Vec<PriceFeedLeg>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxSpreadConfiguredEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub mint_spread_bps: u16,
+    pub redeem_spread_bps: u16,
+    pub spread_recipient: SpreadRecipient,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FxSpreadAppliedEvent {
+    pub sovereign_coin: Pubkey,
+    pub payer: Pubkey,
+    pub is_mint: bool,
+    pub oracle_rate: u64,      // Target currency per USDC, scaled by PRICE_PRECISION
+    pub applied_rate: u64,     // Rate the trade cleared at after the spread
+    pub spread_bps: u16,
+    pub spread_amount: u64,    // USDC set aside for the spread recipient
+    pub spread_recipient: SpreadRecipient,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SpreadRevenueWithdrawnEvent {
+    pub sovereign_coin: Pubkey,
+    pub recipient: Pubkey,
+    pub spread_recipient: SpreadRecipient,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsdcPegGuardUpdatedEvent {
+    pub admin: Pubkey,
+    pub band_bps: u16,
+    pub action: DepegAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UsdcDepegAlertEvent {
+    pub is_depegged: bool,     // False when USDC is back inside the band
+    pub usdc_price: u64,       // USDC/USD scaled by PRICE_PRECISION
+    pub band_bps: u16,
+    pub action: DepegAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RfqSignerConfiguredEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub signer: Pubkey,
+    pub is_enabled: bool,
+    pub max_oracle_deviation_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RfqQuoteFilledEvent {
+    pub sovereign_coin: Pubkey,
+    pub taker: Pubkey,
+    pub signer: Pubkey,
+    pub side: RfqSide,
+    pub amount: u64,
+    pub rate: u64,             // Quoted target currency per USDC, scaled by PRICE_PRECISION
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceHistoryConfiguredEvent {
+    pub sovereign_coin: Pubkey,
+    pub admin: Pubkey,
+    pub crank_interval: i64,
+    pub twap_window: i64,
+    pub max_twap_deviation_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceHistorySampledEvent {
+    pub sovereign_coin: Pubkey,
+    pub keeper: Pubkey,
+    pub mantissa: i128,
+    pub scale: u32,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InKindRedemptionConfiguredEvent {
+    pub sovereign_coin: Pubkey,
+    pub authority: Pubkey,
+    pub is_enabled: bool,
+    pub min_sovereign_amount: u64,
+    pub max_sovereign_amount: u64,
+    pub period_duration: i64,
+    pub period_limit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SovereignCoinRedeemedToBondsEvent {
+    pub payer: Pubkey,
+    pub sovereign_coin: Pubkey,
+    pub sovereign_amount: u64,
+    pub bond_amount: u64,      // Raw bond tokens sent from the coin's bond holding
+    pub bond_book_value: u64,  // Share of the coin's bond_amount, in USDC, released with them
+    pub usdc_amount: u64,      // Share of the USDC reserve paid alongside
+    pub timestamp: i64,
+}
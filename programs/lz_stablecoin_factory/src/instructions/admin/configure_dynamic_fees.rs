@@ -0,0 +1,57 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureDynamicFeesArgs {
+    pub enabled: bool,
+    pub points: Vec<DynamicFeePoint>,  // Ordered by ascending reserve health
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureDynamicFees<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + DynamicFeeConfig::INIT_SPACE,
+        seeds = [b"dynamic_fee"],
+        bump
+    )]
+    pub dynamic_fee_config: Box<Account<'info, DynamicFeeConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_dynamic_fees(
+    ctx: Context<ConfigureDynamicFees>,
+    args: ConfigureDynamicFeesArgs,
+) -> Result<()> {
+    require!(
+        args.points.len() <= MAX_DYNAMIC_FEE_POINTS,
+        StablecoinError::InvalidDynamicFeeCurve
+    );
+
+    let clock = Clock::get()?;
+    let dynamic_fee_config = &mut ctx.accounts.dynamic_fee_config;
+    dynamic_fee_config.bump = ctx.bumps.dynamic_fee_config;
+    dynamic_fee_config.enabled = args.enabled;
+    dynamic_fee_config.point_count = args.points.len() as u8;
+    dynamic_fee_config.points = [DynamicFeePoint::default(); MAX_DYNAMIC_FEE_POINTS];
+    dynamic_fee_config.points[..args.points.len()].copy_from_slice(&args.points);
+    dynamic_fee_config.updated_at = clock.unix_timestamp;
+    dynamic_fee_config.validate()?;
+
+    emit_cpi!(DynamicFeesUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        enabled: args.enabled,
+        points: args.points,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureFeeCurveArgs {
+    pub curve: EtherfuseFeeCurve,
+    pub tax_withholding_bps: u16,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureFeeCurve<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + FeeCurveConfig::INIT_SPACE,
+        seeds = [b"fee_curve"],
+        bump
+    )]
+    pub fee_curve: Box<Account<'info, FeeCurveConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_fee_curve(
+    ctx: Context<ConfigureFeeCurve>,
+    args: ConfigureFeeCurveArgs,
+) -> Result<()> {
+    args.curve.validate()?;
+    require!(
+        args.tax_withholding_bps <= BASIS_POINT_MAX,
+        StablecoinError::InvalidTaxWithholding
+    );
+
+    let clock = Clock::get()?;
+    let fee_curve = &mut ctx.accounts.fee_curve;
+    fee_curve.bump = ctx.bumps.fee_curve;
+    fee_curve.curve = args.curve;
+    fee_curve.tax_withholding_bps = args.tax_withholding_bps;
+    fee_curve.updated_at = clock.unix_timestamp;
+
+    emit_cpi!(FeeCurveUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        low_yield_threshold_bps: args.curve.low_yield_threshold_bps,
+        high_yield_threshold_bps: args.curve.high_yield_threshold_bps,
+        low_fee_bps: args.curve.low_fee_bps,
+        high_fee_bps: args.curve.high_fee_bps,
+        slope: args.curve.slope,
+        intercept: args.curve.intercept,
+        tax_withholding_bps: args.tax_withholding_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
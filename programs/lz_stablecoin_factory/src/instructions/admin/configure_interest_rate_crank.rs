@@ -0,0 +1,86 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureInterestRateCrankArgs {
+    pub update_interval: i64,
+    pub max_rate_step_bps: u16,
+    pub rate_floor: i16,
+    pub rate_ceiling: i16,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureInterestRateCrank<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_interest_bearing @ StablecoinError::NotInterestBearing,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + InterestRateConfig::INIT_SPACE,
+        seeds = [b"interest_rate_config", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub interest_rate_config: Box<Account<'info, InterestRateConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_interest_rate_crank(
+    ctx: Context<ConfigureInterestRateCrank>,
+    args: ConfigureInterestRateCrankArgs,
+) -> Result<()> {
+    require!(
+        args.update_interval >= MIN_INTEREST_RATE_UPDATE_INTERVAL,
+        StablecoinError::InvalidInterestRateInterval
+    );
+    require!(
+        args.rate_floor <= args.rate_ceiling,
+        StablecoinError::InvalidInterestRateBounds
+    );
+    require!(
+        args.max_rate_step_bps > 0 && args.max_rate_step_bps <= BASIS_POINT_MAX,
+        StablecoinError::InvalidFeeBasisPoints
+    );
+
+    let interest_rate_config = &mut ctx.accounts.interest_rate_config;
+    interest_rate_config.bump = ctx.bumps.interest_rate_config;
+    interest_rate_config.sovereign_coin = ctx.accounts.sovereign_coin.key();
+    interest_rate_config.update_interval = args.update_interval;
+    interest_rate_config.max_rate_step_bps = args.max_rate_step_bps;
+    interest_rate_config.rate_floor = args.rate_floor;
+    interest_rate_config.rate_ceiling = args.rate_ceiling;
+
+    let clock = Clock::get()?;
+    emit_cpi!(InterestRateConfigUpdatedEvent {
+        sovereign_coin: ctx.accounts.sovereign_coin.key(),
+        admin: ctx.accounts.admin.key(),
+        update_interval: args.update_interval,
+        max_rate_step_bps: args.max_rate_step_bps,
+        rate_floor: args.rate_floor,
+        rate_ceiling: args.rate_ceiling,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
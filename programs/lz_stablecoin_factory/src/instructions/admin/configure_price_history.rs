@@ -0,0 +1,79 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigurePriceHistoryArgs {
+    pub crank_interval: i64,
+    pub twap_window: i64,
+    pub max_twap_deviation_bps: u16,  // 0 records trades without checking them against the TWAP
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigurePriceHistory<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PriceHistory::INIT_SPACE,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_price_history(
+    ctx: Context<ConfigurePriceHistory>,
+    args: ConfigurePriceHistoryArgs,
+) -> Result<()> {
+    require!(
+        args.crank_interval >= MIN_PRICE_HISTORY_CRANK_INTERVAL && args.twap_window > 0,
+        StablecoinError::InvalidPriceHistoryConfig
+    );
+    require!(
+        args.max_twap_deviation_bps <= BASIS_POINT_MAX,
+        StablecoinError::InvalidFeeBasisPoints
+    );
+
+    // Recorded samples are kept, only the settings change
+    let price_history = &mut ctx.accounts.price_history;
+    price_history.bump = ctx.bumps.price_history;
+    price_history.sovereign_coin = ctx.accounts.sovereign_coin.key();
+    price_history.crank_interval = args.crank_interval;
+    price_history.twap_window = args.twap_window;
+    price_history.max_twap_deviation_bps = args.max_twap_deviation_bps;
+
+    let clock = Clock::get()?;
+    emit_cpi!(PriceHistoryConfiguredEvent {
+        sovereign_coin: ctx.accounts.sovereign_coin.key(),
+        admin: ctx.accounts.admin.key(),
+        crank_interval: args.crank_interval,
+        twap_window: args.twap_window,
+        max_twap_deviation_bps: args.max_twap_deviation_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
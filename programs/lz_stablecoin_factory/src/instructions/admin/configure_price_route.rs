@@ -0,0 +1,59 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigurePriceRouteArgs {
+    pub fiat_currency: String,
+    pub legs: Vec<PriceFeedLeg>,  // In route order, starting from USDC
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: ConfigurePriceRouteArgs)]
+pub struct ConfigurePriceRoute<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PriceRoute::INIT_SPACE,
+        seeds = [b"price_route", args.fiat_currency.as_bytes()],
+        bump
+    )]
+    pub price_route: Box<Account<'info, PriceRoute>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_price_route(
+    ctx: Context<ConfigurePriceRoute>,
+    args: ConfigurePriceRouteArgs,
+) -> Result<()> {
+    require!(args.fiat_currency.len() <= 8, StablecoinError::FiatCurrencyTooLong);
+    require!(
+        args.legs.len() <= MAX_PRICE_ROUTE_LEGS,
+        StablecoinError::InvalidPriceRoute
+    );
+
+    let clock = Clock::get()?;
+    let price_route = &mut ctx.accounts.price_route;
+    price_route.bump = ctx.bumps.price_route;
+    price_route.fiat_currency = currency_code(&args.fiat_currency);
+    price_route.leg_count = args.legs.len() as u8;
+    price_route.legs = [PriceFeedLeg::default(); MAX_PRICE_ROUTE_LEGS];
+    price_route.legs[..args.legs.len()].copy_from_slice(&args.legs);
+    price_route.updated_at = clock.unix_timestamp;
+    price_route.validate()?;
+
+    emit_cpi!(PriceRouteUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        fiat_currency: args.fiat_currency,
+        legs: args.legs,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureReservePolicyArgs {
+    pub curve_kind: ReserveCurveKind,
+    pub rating_steps_bps: [u16; 10],
+    pub convex_coefficient_bps: u16,
+    pub floor_bps: u16,
+    pub cap_bps: u16,
+    pub concentration_threshold_bps: u16,
+    pub concentration_surcharge_bps: u16,
+    pub min_mint_collateral_ratio_bps: u16,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureReservePolicy<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + ReservePolicy::INIT_SPACE,
+        seeds = [b"reserve_policy"],
+        bump
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_reserve_policy(
+    ctx: Context<ConfigureReservePolicy>,
+    args: ConfigureReservePolicyArgs,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let reserve_policy = &mut ctx.accounts.reserve_policy;
+    reserve_policy.bump = ctx.bumps.reserve_policy;
+    reserve_policy.curve_kind = args.curve_kind;
+    reserve_policy.rating_steps_bps = args.rating_steps_bps;
+    reserve_policy.convex_coefficient_bps = args.convex_coefficient_bps;
+    reserve_policy.floor_bps = args.floor_bps;
+    reserve_policy.cap_bps = args.cap_bps;
+    reserve_policy.concentration_threshold_bps = args.concentration_threshold_bps;
+    reserve_policy.concentration_surcharge_bps = args.concentration_surcharge_bps;
+    reserve_policy.min_mint_collateral_ratio_bps = args.min_mint_collateral_ratio_bps;
+    reserve_policy.updated_at = clock.unix_timestamp;
+    reserve_policy.validate()?;
+
+    emit_cpi!(ReservePolicyUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        curve_kind: args.curve_kind,
+        rating_steps_bps: args.rating_steps_bps,
+        convex_coefficient_bps: args.convex_coefficient_bps,
+        floor_bps: args.floor_bps,
+        cap_bps: args.cap_bps,
+        concentration_threshold_bps: args.concentration_threshold_bps,
+        concentration_surcharge_bps: args.concentration_surcharge_bps,
+        min_mint_collateral_ratio_bps: args.min_mint_collateral_ratio_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureUsdcPegGuardArgs {
+    pub band_bps: u16,
+    pub action: DepegAction,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureUsdcPegGuard<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + UsdcPegGuard::INIT_SPACE,
+        seeds = [b"usdc_peg_guard"],
+        bump
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_configure_usdc_peg_guard(
+    ctx: Context<ConfigureUsdcPegGuard>,
+    args: ConfigureUsdcPegGuardArgs,
+) -> Result<()> {
+    require!(
+        args.band_bps > 0 && args.band_bps < BASIS_POINT_MAX,
+        StablecoinError::InvalidPegBand
+    );
+
+    let clock = Clock::get()?;
+    let usdc_peg_guard = &mut ctx.accounts.usdc_peg_guard;
+    usdc_peg_guard.bump = ctx.bumps.usdc_peg_guard;
+    usdc_peg_guard.band_bps = args.band_bps;
+    usdc_peg_guard.action = args.action;
+    usdc_peg_guard.updated_at = clock.unix_timestamp;
+
+    emit_cpi!(UsdcPegGuardUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        band_bps: args.band_bps,
+        action: args.action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -56,6 +56,7 @@ impl InitializeFactory<'_> {
         
         factory.total_sovereign_coins = 0;
         factory.total_supply_all_coins = 0;
+        factory.total_backing_value = 0;
         
         factory.bond_rating_ordinals = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
         
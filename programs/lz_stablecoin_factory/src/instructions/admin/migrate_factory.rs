@@ -0,0 +1,64 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateFactory<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Factory still in the legacy layout, so it can't be loaded as an Account.
+    /// Its discriminator and size are checked by the handler before it is rewritten.
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub factory: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow the factory created before the upgrade to the current Factory layout. Runs once,
+/// before any coin is migrated, every instruction that loads the factory fails until it has.
+pub fn handle_migrate_factory(ctx: Context<MigrateFactory>) -> Result<()> {
+    let factory_info = ctx.accounts.factory.to_account_info();
+    let old_size = factory_info.data_len();
+    let new_size = 8 + Factory::INIT_SPACE;
+
+    let legacy = {
+        let data = factory_info.try_borrow_data()?;
+        require!(
+            old_size <= 8 + LegacyFactory::INIT_SPACE && data[..8] == Factory::DISCRIMINATOR,
+            StablecoinError::AccountAlreadyMigrated
+        );
+        LegacyFactory::deserialize(&mut &data[8..])?
+    };
+
+    grow_account(
+        &factory_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    let factory = legacy.into_current();
+    {
+        let mut data = factory_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        factory.try_serialize(&mut writer)?;
+    }
+
+    emit_cpi!(FactoryMigratedEvent {
+        factory: factory_info.key(),
+        admin: ctx.accounts.admin.key(),
+        old_size: old_size as u64,
+        new_size: new_size as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(fiat_currency: [u8; 8])]
+pub struct MigrateSovereignCoin<'info> {
+    #[account(
+        mut,
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    // Must already be migrated itself, see migrate_factory
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    /// CHECK: Sovereign coin still in the legacy layout, so it can't be loaded as an Account.
+    /// Its discriminator, size and address are checked by the handler before it is rewritten.
+    #[account(
+        mut,
+        owner = crate::ID,
+    )]
+    pub sovereign_coin: UncheckedAccount<'info>,
+
+    // Keyed by the coin's currency, passed in the args since the coin can't be read yet
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &fiat_currency[..fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    // Coins created before the upgrade never had one, every mint, redeem, bridge and fee path requires it
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SovereignCoinStats::INIT_SPACE,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    // Mints and redemptions check their price against it, it starts on the defaults a new coin gets
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + PriceHistory::INIT_SPACE,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a coin created before the upgrade to the current SovereignCoin layout, fill in the fields
+/// it didn't have, count its supply and backing into its CurrencyStats and the factory totals and
+/// open its SovereignCoinStats and PriceHistory. Lifetime counters start from the migration, earlier activity isn't replayed.
+/// Every instruction that loads the coin fails until this has run.
+pub fn handle_migrate_sovereign_coin(ctx: Context<MigrateSovereignCoin>, fiat_currency: [u8; 8]) -> Result<()> {
+    let sovereign_coin_info = ctx.accounts.sovereign_coin.to_account_info();
+    let old_size = sovereign_coin_info.data_len();
+    let new_size = 8 + SovereignCoin::INIT_SPACE;
+
+    let legacy = {
+        let data = sovereign_coin_info.try_borrow_data()?;
+        require!(
+            old_size <= 8 + LegacySovereignCoin::INIT_SPACE && data[..8] == SovereignCoin::DISCRIMINATOR,
+            StablecoinError::AccountAlreadyMigrated
+        );
+        LegacySovereignCoin::deserialize(&mut &data[8..])?
+    };
+    require!(legacy.target_fiat_currency == fiat_currency, StablecoinError::InvalidFiatCurrency);
+
+    let symbol_len = legacy.symbol.iter().position(|&x| x == 0).unwrap_or(8);
+    let expected_address = Pubkey::create_program_address(
+        &[
+            b"sovereign_coin",
+            ctx.accounts.factory.key().as_ref(),
+            &legacy.symbol[..symbol_len],
+            &[legacy.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| error!(anchor_lang::error::ErrorCode::ConstraintSeeds))?;
+    require_keys_eq!(
+        expected_address,
+        sovereign_coin_info.key(),
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    grow_account(
+        &sovereign_coin_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    let clock = Clock::get()?;
+    let mut sovereign_coin = legacy.into_current();
+    ctx.accounts.currency_stats.track_coin(
+        ctx.bumps.currency_stats,
+        &mut sovereign_coin,
+        &mut ctx.accounts.factory,
+        clock.unix_timestamp,
+    )?;
+    let sovereign_coin_stats = &mut ctx.accounts.sovereign_coin_stats;
+    sovereign_coin_stats.bump = ctx.bumps.sovereign_coin_stats;
+    sovereign_coin_stats.sovereign_coin = sovereign_coin_info.key();
+    ctx.accounts.price_history.initialize(ctx.bumps.price_history, sovereign_coin_info.key());
+
+    {
+        let mut data = sovereign_coin_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        sovereign_coin.try_serialize(&mut writer)?;
+    }
+
+    emit_cpi!(SovereignCoinMigratedEvent {
+        sovereign_coin: sovereign_coin_info.key(),
+        admin: ctx.accounts.admin.key(),
+        old_size: old_size as u64,
+        new_size: new_size as u64,
+        yield_mode: sovereign_coin.yield_mode,
+        fiat_currency,
+        total_supply: sovereign_coin.total_supply,
+        usdc_amount: sovereign_coin.usdc_amount,
+        bond_amount: sovereign_coin.bond_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Realloc a program owned account to `new_size`, with the payer topping up its rent.
+/// The new bytes are zeroed so nothing from an earlier, longer layout is left behind.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    new_size: usize,
+) -> Result<()> {
+    let required_lamports = (Rent::get()?).minimum_balance(new_size);
+    let current_lamports = account.lamports();
+
+    if required_lamports > current_lamports {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports.safe_sub(current_lamports)?,
+        )?;
+    }
+
+    account.realloc(new_size, true)?;
+
+    Ok(())
+}
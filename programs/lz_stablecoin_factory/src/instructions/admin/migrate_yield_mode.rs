@@ -0,0 +1,94 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateYieldMode<'info> {
+    #[account(
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+/// Deposits and unclaimed rewards survive a migration away from claimable mode,
+/// holders can still withdraw and claim what was earned before the switch
+pub fn handle_migrate_yield_mode(
+    ctx: Context<MigrateYieldMode>,
+    yield_mode: YieldMode,
+    reward_payout: RewardPayout,
+) -> Result<()> {
+    let sovereign_coin = &ctx.accounts.sovereign_coin;
+    let old_yield_mode = sovereign_coin.yield_mode;
+    require!(
+        old_yield_mode != yield_mode || sovereign_coin.reward_payout != reward_payout,
+        StablecoinError::InvalidYieldModeMigration
+    );
+    require!(
+        yield_mode != YieldMode::InterestBearing || sovereign_coin.is_interest_bearing,
+        StablecoinError::InvalidYieldModeMigration
+    );
+
+    // Everything up to now is booked under the old mode
+    let clock = Clock::get()?;
+    ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+
+    // Leaving interest-bearing mode stops the mint accruing, the next crank sets the rate when entering it
+    if old_yield_mode == YieldMode::InterestBearing
+        && yield_mode != YieldMode::InterestBearing
+        && ctx.accounts.sovereign_coin.is_interest_bearing
+    {
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+        token_extension::update_interest_rate_signed(
+            &ctx.accounts.factory.to_account_info(),
+            factory_signer,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_2022_program,
+            0,
+        )?;
+        ctx.accounts.sovereign_coin.interest_rate = 0;
+    }
+
+    let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+    sovereign_coin.yield_mode = yield_mode;
+    sovereign_coin.reward_payout = reward_payout;
+
+    emit_cpi!(YieldModeMigratedEvent {
+        sovereign_coin: sovereign_coin.key(),
+        admin: ctx.accounts.admin.key(),
+        old_yield_mode,
+        new_yield_mode: yield_mode,
+        reward_payout,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
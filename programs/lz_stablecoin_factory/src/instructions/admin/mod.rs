@@ -11,6 +11,17 @@ pub mod update_transfer_fee;
 pub mod harvest_transfer_fee;
 pub mod withdraw_transfer_fee;
 pub mod update_interest_rate;
+pub mod configure_interest_rate_crank;
+pub mod configure_fee_curve;
+pub mod set_currency_tax_rate;
+pub mod migrate_yield_mode;
+pub mod migrate_factory;
+pub mod migrate_sovereign_coin;
+pub mod configure_reserve_policy;
+pub mod configure_dynamic_fees;
+pub mod configure_usdc_peg_guard;
+pub mod configure_price_route;
+pub mod configure_price_history;
 pub mod setup_usdc_accounts;
 pub mod withdraw_sovereign_coin_fees;
 
@@ -25,5 +36,16 @@ pub use update_transfer_fee::*;
 pub use harvest_transfer_fee::*;
 pub use withdraw_transfer_fee::*;
 pub use update_interest_rate::*;
+pub use configure_interest_rate_crank::*;
+pub use configure_fee_curve::*;
+pub use set_currency_tax_rate::*;
+pub use migrate_yield_mode::*;
+pub use migrate_factory::*;
+pub use migrate_sovereign_coin::*;
+pub use configure_reserve_policy::*;
+pub use configure_dynamic_fees::*;
+pub use configure_usdc_peg_guard::*;
+pub use configure_price_route::*;
+pub use configure_price_history::*;
 pub use setup_usdc_accounts::*;
 pub use withdraw_sovereign_coin_fees::*;
@@ -0,0 +1,47 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCurrencyTaxRate<'info> {
+    #[account(
+        constraint = is_admin(&admin.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_curve"],
+        bump = fee_curve.bump,
+    )]
+    pub fee_curve: Box<Account<'info, FeeCurveConfig>>,
+}
+
+/// Passing `None` removes the override so the currency falls back to the default withholding
+pub fn handle_set_currency_tax_rate(
+    ctx: Context<SetCurrencyTaxRate>,
+    fiat_currency: String,
+    tax_withholding_bps: Option<u16>,
+) -> Result<()> {
+    let fiat_bytes = fiat_currency.as_bytes();
+    require!(fiat_bytes.len() <= 8, StablecoinError::FiatCurrencyTooLong);
+    if let Some(bps) = tax_withholding_bps {
+        require!(bps <= BASIS_POINT_MAX, StablecoinError::InvalidTaxWithholding);
+    }
+
+    let mut currency = [0u8; 8];
+    currency[..fiat_bytes.len()].copy_from_slice(fiat_bytes);
+
+    let clock = Clock::get()?;
+    let fee_curve = &mut ctx.accounts.fee_curve;
+    fee_curve.set_currency_tax(currency, tax_withholding_bps)?;
+    fee_curve.updated_at = clock.unix_timestamp;
+
+    emit_cpi!(CurrencyTaxRateUpdatedEvent {
+        admin: ctx.accounts.admin.key(),
+        fiat_currency,
+        tax_withholding_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -44,13 +44,34 @@ pub struct UpdateInterestRate<'info> {
         constraint = bond_holding.mint == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
     )]
     pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
-    
+
+    // Required for basket coins, constituent (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
+    #[account(
+        seeds = [b"fee_curve"],
+        bump = fee_curve.bump,
+    )]
+    pub fee_curve: Box<Account<'info, FeeCurveConfig>>,
+
     pub token_2022_program: Program<'info, Token2022>,
 }
 
     pub fn handle_update_interest_rate(ctx: Context<UpdateInterestRate>, manual_rate: Option<i16>) -> Result<()> {
-        let new_rate = if let Some(rate) = manual_rate {
+        let fee_curve = &ctx.accounts.fee_curve;
+        let tax_withholding_bps = fee_curve.tax_withholding_bps_for(&ctx.accounts.sovereign_coin.target_fiat_currency);
+        // A manual rate overrides the net bond rate, the holders' share still applies to it
+        let net_rate = if let Some(rate) = manual_rate {
             rate
+        } else if ctx.accounts.sovereign_coin.is_basket {
+            let bond_basket = ctx.accounts.bond_basket
+                .as_ref()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            bond_basket.weighted_interest_rate(ctx.remaining_accounts, &fee_curve.curve, tax_withholding_bps)?
         } else {
             let bond_rate = token_extension::read_current_interest_rate(
                 &ctx.accounts.bond_token_mint.to_account_info()
@@ -59,8 +80,12 @@ pub struct UpdateInterestRate<'info> {
             interest::calculate_sovereign_interest_rate(
                 bond_rate,
                 ctx.accounts.bond_holding.amount,
+                &fee_curve.curve,
+                tax_withholding_bps,
             )?
         };
+        let new_rate = ctx.accounts.sovereign_coin.applied_interest_rate(net_rate, ctx.accounts.factory.yield_share_holders)?;
+
         let factory_seeds = &[
             b"factory".as_ref(),
             &[ctx.accounts.factory.bump],
@@ -76,10 +101,12 @@ pub struct UpdateInterestRate<'info> {
         
         let old_rate = ctx.accounts.sovereign_coin.interest_rate;
         
+        let clock = Clock::get()?;
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
         sovereign_coin.interest_rate = new_rate;
+        sovereign_coin.net_yield_rate = net_rate;
         
-        let clock = Clock::get()?;
         emit_cpi!(SovereignCoinInterestRateUpdatedEvent {
             sovereign_coin: sovereign_coin.key(),
             mint: sovereign_coin.mint,
@@ -7,6 +7,24 @@ pub struct WithdrawFromSovereignCoinProtocolVault<'info> {
         bump = factory.bump,
     )]
     pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.mint == mint.key() @ StablecoinError::InvalidSovereignCoinMint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
     
     #[account(
         mut,
@@ -63,6 +81,8 @@ pub fn handle_withdraw_from_sovereign_coin_account(
         amount,
         ctx.accounts.mint.decimals,
     )?;
+
+    ctx.accounts.sovereign_coin_stats.record_fees_withdrawn(amount, Clock::get()?.unix_timestamp)?;
     
     Ok(())
 }
\ No newline at end of file
@@ -10,6 +10,24 @@ pub struct WithdrawFees<'info> {
         bump = factory.bump,
     )]
     pub factory: Account<'info, Factory>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.mint == mint.key() @ StablecoinError::InvalidSovereignCoinMint,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
     
     #[account(
         mut,
@@ -43,6 +61,8 @@ pub fn handle_withdraw_fees(
         &[ctx.accounts.factory.bump],
     ];
     let factory_signer = &[&factory_seeds[..]];
+    let vault_balance_before = ctx.accounts.sovereign_coin_protocol_vault.amount;
+
     token_extension::withdraw_fees_signed(
         &ctx.accounts.factory.to_account_info(),
         factory_signer,
@@ -50,6 +70,10 @@ pub fn handle_withdraw_fees(
         &ctx.accounts.mint.to_account_info(),
         &ctx.accounts.sovereign_coin_protocol_vault.to_account_info(),
     )?;
+
+    ctx.accounts.sovereign_coin_protocol_vault.reload()?;
+    let harvested = ctx.accounts.sovereign_coin_protocol_vault.amount.safe_sub(vault_balance_before)?;
+    ctx.accounts.sovereign_coin_stats.record_transfer_fees_harvested(harvested, Clock::get()?.unix_timestamp)?;
     
     Ok(())
 }
\ No newline at end of file
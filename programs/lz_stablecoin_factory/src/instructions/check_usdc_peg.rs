@@ -0,0 +1,69 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CheckUsdcPeg<'info> {
+    // Anyone can check, the reading comes straight from the factory's base feed
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [b"usdc_peg_guard"],
+        bump = usdc_peg_guard.bump,
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    /// CHECK: USDC/USD oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+}
+
+impl CheckUsdcPeg<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let usdc_price = switchboard::get_usdc_usd_price(&ctx.accounts.payment_base_price_feed_account.to_account_info())?;
+
+        let usdc_peg_guard = &mut ctx.accounts.usdc_peg_guard;
+        // Pause mode reverts the trades themselves, so the alert for a depeg comes from here
+        if usdc_peg_guard.record_price(usdc_price, clock.unix_timestamp)? {
+            emit_cpi!(UsdcDepegAlertEvent {
+                is_depegged: usdc_peg_guard.is_depegged,
+                usdc_price,
+                band_bps: usdc_peg_guard.band_bps,
+                action: usdc_peg_guard.action,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Peg check run by USD coin mints and redemptions before converting one-to-one, with the
+/// USDC/USD reading the trade is priced from. Returns the USDC/USD price to convert at when
+/// the trade must be oracle priced.
+pub fn guard_usd_conversion(
+    usdc_peg_guard: &mut UsdcPegGuard,
+    usdc_price: u64,
+    unix_timestamp: i64,
+) -> Result<Option<u64>> {
+    if usdc_peg_guard.record_price(usdc_price, unix_timestamp)? {
+        emit!(UsdcDepegAlertEvent {
+            is_depegged: usdc_peg_guard.is_depegged,
+            usdc_price,
+            band_bps: usdc_peg_guard.band_bps,
+            action: usdc_peg_guard.action,
+            timestamp: unix_timestamp,
+        });
+    }
+
+    usdc_peg_guard.usd_conversion_price()
+}
@@ -0,0 +1,212 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    // Rewards already earned stay claimable after a migration away from claimable mode
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"holder_rewards", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = holder_rewards.bump,
+    )]
+    pub holder_rewards: Box<Account<'info, HolderRewards>>,
+
+    // Receives minted rewards for coins paying out in sovereign coins
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub holder_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Required for coins paying out in USDC
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_usdc_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_token_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ClaimRewards<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            clock.unix_timestamp,
+        )?;
+
+        let holder_rewards = &mut ctx.accounts.holder_rewards;
+        holder_rewards.checkpoint(ctx.accounts.sovereign_coin.reward_index)?;
+
+        let usdc_amount = holder_rewards.pending_rewards;
+        require!(usdc_amount > 0, StablecoinError::NoRewardsToClaim);
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+
+        let sovereign_amount = match ctx.accounts.sovereign_coin.reward_payout {
+            RewardPayout::Usdc => {
+                let holder_usdc_token_account = ctx.accounts.holder_usdc_token_account
+                    .as_ref()
+                    .ok_or(StablecoinError::RewardPayoutAccountRequired)?;
+
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.global_usdc_reserve.to_account_info(),
+                            mint: ctx.accounts.usdc_token_mint.to_account_info(),
+                            to: holder_usdc_token_account.to_account_info(),
+                            authority: ctx.accounts.factory.to_account_info(),
+                        },
+                        factory_signer,
+                    ),
+                    usdc_amount,
+                    ctx.accounts.usdc_token_mint.decimals,
+                )?;
+
+                0
+            }
+            RewardPayout::SovereignCoin => {
+                // Minted at the current backing per coin, the USDC stays in the reserve behind the new supply
+                let sovereign_coin = &ctx.accounts.sovereign_coin;
+                let backing = sovereign_coin.usdc_amount.safe_add(sovereign_coin.bond_amount)?;
+                require!(
+                    backing > 0 && sovereign_coin.total_supply > 0,
+                    StablecoinError::NoBackingForRewardMint
+                );
+                let sovereign_amount = mul_div(
+                    usdc_amount,
+                    sovereign_coin.total_supply,
+                    backing,
+                    Rounding::Down,
+                )?;
+
+                token_interface::mint_to(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_2022_program.to_account_info(),
+                        MintTo {
+                            mint: ctx.accounts.mint.to_account_info(),
+                            to: ctx.accounts.holder_sovereign_coin_account.to_account_info(),
+                            authority: ctx.accounts.factory.to_account_info(),
+                        },
+                        factory_signer,
+                    ),
+                    sovereign_amount,
+                )?;
+
+                let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+                sovereign_coin.total_supply = sovereign_coin.total_supply.safe_add(sovereign_amount)?;
+                sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_add(usdc_amount)?;
+
+                ctx.accounts.factory.increase_total_supply(sovereign_amount)?;
+                ctx.accounts.factory.increase_total_backing(usdc_amount)?;
+                ctx.accounts.currency_stats.record_mint(
+                    sovereign_amount,
+                    usdc_amount,
+                    0,
+                    0,
+                    clock.unix_timestamp,
+                )?;
+                ctx.accounts.sovereign_coin_stats.record_mint(
+                    sovereign_amount,
+                    usdc_amount,
+                    0,
+                    clock.unix_timestamp,
+                )?;
+
+                sovereign_amount
+            }
+        };
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.reward_usdc_balance = sovereign_coin.reward_usdc_balance.safe_sub(usdc_amount)?;
+
+        let holder_rewards = &mut ctx.accounts.holder_rewards;
+        holder_rewards.pending_rewards = 0;
+        holder_rewards.total_claimed = holder_rewards.total_claimed.safe_add(usdc_amount)?;
+        holder_rewards.last_claimed_at = clock.unix_timestamp;
+
+        emit_cpi!(RewardsClaimedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            holder: ctx.accounts.holder.key(),
+            payout: sovereign_coin.reward_payout,
+            usdc_amount,
+            sovereign_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
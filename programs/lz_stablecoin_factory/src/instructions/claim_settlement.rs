@@ -0,0 +1,184 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimSettlementArgs {
+    pub sovereign_amount: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: ClaimSettlementArgs)]
+pub struct ClaimSettlement<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.lifecycle == SovereignCoinLifecycle::Settled @ StablecoinError::SovereignCoinNotSettled,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program,
+        constraint = holder_sovereign_coin_account.amount >= args.sovereign_amount @ StablecoinError::InsufficientBalance
+    )]
+    pub holder_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_token_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl ClaimSettlement<'_> {
+    pub fn handler(ctx: Context<Self>, args: ClaimSettlementArgs) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        require!(args.sovereign_amount > 0, StablecoinError::InvalidAmount);
+
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        require!(
+            args.sovereign_amount <= sovereign_coin.total_supply,
+            StablecoinError::InsufficientBalance
+        );
+
+        // Pro-rata share of what is left, rounded down so later claimants are never short
+        let usdc_amount = mul_div(
+            args.sovereign_amount,
+            sovereign_coin.usdc_amount,
+            sovereign_coin.total_supply,
+            Rounding::Down,
+        )?;
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.holder_sovereign_coin_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            args.sovereign_amount,
+        )?;
+
+        if usdc_amount > 0 {
+            let factory_seeds = &[
+                b"factory".as_ref(),
+                &[ctx.accounts.factory.bump],
+            ];
+            let factory_signer = &[&factory_seeds[..]];
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.global_usdc_reserve.to_account_info(),
+                        mint: ctx.accounts.usdc_token_mint.to_account_info(),
+                        to: ctx.accounts.holder_usdc_token_account.to_account_info(),
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                usdc_amount,
+                ctx.accounts.usdc_token_mint.decimals,
+            )?;
+        }
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.safe_sub(args.sovereign_amount)?;
+        sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_sub(usdc_amount)?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.factory.decrease_total_supply(args.sovereign_amount)?;
+        ctx.accounts.factory.decrease_total_backing(usdc_amount)?;
+        ctx.accounts.currency_stats.record_redeem(
+            args.sovereign_amount,
+            usdc_amount,
+            0,
+            0,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_settlement_claim(
+            args.sovereign_amount,
+            usdc_amount,
+            clock.unix_timestamp,
+        )?;
+
+        emit_cpi!(SovereignCoinSettlementClaimedEvent {
+            holder: ctx.accounts.holder.key(),
+            sovereign_coin: sovereign_coin.key(),
+            sovereign_amount: args.sovereign_amount,
+            usdc_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSovereignCoin<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Issuer who funded the coin accounts, receives the reclaimed rent
+    #[account(
+        mut,
+        address = sovereign_coin.creator @ StablecoinError::Unauthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+        constraint = sovereign_coin.lifecycle == SovereignCoinLifecycle::Settled @ StablecoinError::SovereignCoinNotSettled,
+        close = creator
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = currency_stats.bump,
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+        close = creator
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Protocol owned coins are burned here, they would otherwise hold the supply open forever
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = factory,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub sovereign_coin_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the settlement rounding and unclaimed rewards left once every coin is claimed
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the spread revenue still accrued for coins paying their spread to the issuer
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = sovereign_coin.creator,
+    )]
+    pub issuer_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_token_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bond_token_mint,
+        associated_token::authority = factory,
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+        constraint = bond_holding.amount == 0 @ StablecoinError::BondAccountsNotEmpty,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bond_token_mint,
+        associated_token::authority = factory,
+        constraint = bond_ownership.key() == sovereign_coin.bond_ownership @ StablecoinError::InvalidBondHolding,
+        constraint = bond_ownership.amount == 0 @ StablecoinError::BondAccountsNotEmpty,
+    )]
+    pub bond_ownership: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Per-coin configuration accounts, passed when they exist so their rent is reclaimed too
+    #[account(
+        mut,
+        seeds = [b"interest_rate_config", sovereign_coin.key().as_ref()],
+        bump = interest_rate_config.bump,
+        close = creator
+    )]
+    pub interest_rate_config: Option<Box<Account<'info, InterestRateConfig>>>,
+
+    // Required for basket coins
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+        close = creator
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
+    #[account(
+        mut,
+        seeds = [b"rfq_signer", sovereign_coin.key().as_ref()],
+        bump = rfq_signer.bump,
+        close = creator
+    )]
+    pub rfq_signer: Option<Box<Account<'info, RfqQuoteSigner>>>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+        close = creator
+    )]
+    pub price_history: Option<Box<Account<'info, PriceHistory>>>,
+
+    #[account(
+        mut,
+        seeds = [b"in_kind_redemption", sovereign_coin.key().as_ref()],
+        bump = in_kind_redemption_config.bump,
+        close = creator
+    )]
+    pub in_kind_redemption_config: Option<Box<Account<'info, InKindRedemptionConfig>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl CloseSovereignCoin<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+        let clock = Clock::get()?;
+
+        require!(
+            !ctx.accounts.sovereign_coin.is_basket || ctx.accounts.bond_basket.is_some(),
+            StablecoinError::BondBasketRequired
+        );
+
+        let protocol_supply_burned = ctx.accounts.sovereign_coin_protocol_vault.amount;
+        if protocol_supply_burned > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.sovereign_coin_protocol_vault.to_account_info(),
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                protocol_supply_burned,
+            )?;
+        }
+
+        // Every coin has to be claimed before its settlement USDC can leave the reserve
+        let unclaimed_supply = ctx.accounts.sovereign_coin.total_supply.safe_sub(protocol_supply_burned)?;
+        require!(unclaimed_supply == 0, StablecoinError::OutstandingSupply);
+
+        // Spread revenue is paid to its recipient, what is left of the settlement USDC is rounding
+        // and the reward balance is what depositors left unclaimed, both go to the protocol vault
+        let spread_revenue_paid = ctx.accounts.sovereign_coin.spread_revenue_accrued;
+        let spread_recipient = match ctx.accounts.sovereign_coin.spread_recipient {
+            SpreadRecipient::Protocol => ctx.accounts.usdc_protocol_vault.to_account_info(),
+            SpreadRecipient::Issuer => ctx.accounts.issuer_usdc_token_account.to_account_info(),
+        };
+        let unclaimed_usdc = ctx.accounts.sovereign_coin.usdc_amount;
+        let reward_usdc_swept = ctx.accounts.sovereign_coin.reward_usdc_balance;
+
+        for (destination, amount) in [
+            (spread_recipient, spread_revenue_paid),
+            (ctx.accounts.usdc_protocol_vault.to_account_info(), unclaimed_usdc.safe_add(reward_usdc_swept)?),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.global_usdc_reserve.to_account_info(),
+                        mint: ctx.accounts.usdc_token_mint.to_account_info(),
+                        to: destination,
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                amount,
+                ctx.accounts.usdc_token_mint.decimals,
+            )?;
+        }
+
+        // Reclaim rent from the bond token accounts, the coin PDA itself is closed by Anchor
+        for bond_account in [
+            ctx.accounts.bond_holding.to_account_info(),
+            ctx.accounts.bond_ownership.to_account_info(),
+        ] {
+            token_interface::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: bond_account,
+                    destination: ctx.accounts.creator.to_account_info(),
+                    authority: ctx.accounts.factory.to_account_info(),
+                },
+                factory_signer,
+            ))?;
+        }
+
+        let retired_supply = ctx.accounts.sovereign_coin.total_supply;
+        let factory = &mut ctx.accounts.factory;
+        factory.total_sovereign_coins = factory.total_sovereign_coins.safe_sub(1)?;
+        factory.decrease_total_supply(retired_supply)?;
+        factory.decrease_total_backing(unclaimed_usdc)?;
+
+        ctx.accounts.currency_stats.record_close(retired_supply, unclaimed_usdc, clock.unix_timestamp)?;
+
+        // The account is closed by Anchor, the lifecycle change only lives on in the event
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        emit_cpi!(SovereignCoinLifecycleChangedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            old_lifecycle: SovereignCoinLifecycle::Settled,
+            new_lifecycle: SovereignCoinLifecycle::Closed,
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit_cpi!(SovereignCoinClosedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            rent_receiver: ctx.accounts.creator.key(),
+            remaining_sovereign_coins: factory.total_sovereign_coins,
+            protocol_supply_burned,
+            unclaimed_usdc,
+            reward_usdc_swept,
+            spread_revenue_paid,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ pub struct CompleteNFTRedemption<'info> {
     pub payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -23,6 +24,25 @@ pub struct CompleteNFTRedemption<'info> {
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     #[account(
         mut,
         seeds = [b"redeem_state", payer.key().as_ref(), sovereign_coin.key().as_ref()],
@@ -94,6 +114,14 @@ pub struct CompleteNFTRedemption<'info> {
     )]
     pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
     
+    // Keeps the oracle price the redemption was checked at once it has gone through
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub token_2022_program: Program<'info, Token2022>,
@@ -104,6 +132,13 @@ pub struct CompleteNFTRedemption<'info> {
 
 impl CompleteNFTRedemption<'_> {
     pub fn handler(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         let redeem_state = &ctx.accounts.redeem_state;
 
@@ -203,9 +238,34 @@ impl CompleteNFTRedemption<'_> {
         )?;
 
         // Update sovereign coin state
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
         sovereign_coin.total_supply = sovereign_coin.total_supply.safe_sub(redeem_state.sovereign_amount)?;
         sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_sub(redeem_state.from_usdc_reserve)?;
         sovereign_coin.bond_amount = sovereign_coin.bond_amount.safe_sub(redeem_state.from_bond_redemption)?;
+        let spread_booked = sovereign_coin.book_redeem_spread(redeem_state.spread_amount)?;
+
+        ctx.accounts.factory.decrease_total_supply(redeem_state.sovereign_amount)?;
+        ctx.accounts.factory.decrease_total_backing(redeem_state.from_usdc_reserve.safe_add(redeem_state.from_bond_redemption)?)?;
+        ctx.accounts.currency_stats.record_redeem(
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve,
+            redeem_state.from_bond_redemption,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        // The bond leg is paid out as an NFT, so only the USDC legs count as paid out
+        ctx.accounts.sovereign_coin_stats.record_redeem(
+            RedemptionTypeState::NFTBondRedemption,
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve.safe_add(redeem_state.from_protocol_vault)?,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.price_history.record_trade_price(
+            (redeem_state.oracle_price_mantissa, redeem_state.oracle_price_scale),
+            PriceSampleSource::Redeem,
+            &clock,
+        );
 
         // Verification
         ctx.accounts.user_sovereign_coin_account.reload()?;
@@ -235,6 +295,18 @@ impl CompleteNFTRedemption<'_> {
             redemption_type: RedemptionTypeState::NFTBondRedemption,
         });
 
+        emit_cpi!(FxSpreadAppliedEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            payer: ctx.accounts.payer.key(),
+            is_mint: false,
+            oracle_rate: redeem_state.oracle_rate,
+            applied_rate: redeem_state.applied_rate,
+            spread_bps: redeem_state.spread_bps,
+            spread_amount: spread_booked,
+            spread_recipient: ctx.accounts.sovereign_coin.spread_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureFxSpreadArgs {
+    pub mint_spread_bps: u16,
+    pub redeem_spread_bps: u16,
+    pub spread_recipient: SpreadRecipient,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureFxSpread<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+}
+
+impl ConfigureFxSpread<'_> {
+    pub fn handler(ctx: Context<Self>, args: ConfigureFxSpreadArgs) -> Result<()> {
+        require!(
+            args.mint_spread_bps <= MAX_FX_SPREAD_BPS && args.redeem_spread_bps <= MAX_FX_SPREAD_BPS,
+            StablecoinError::InvalidFxSpread
+        );
+
+        // Revenue already accrued is paid to whoever is the recipient when it is withdrawn
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.mint_spread_bps = args.mint_spread_bps;
+        sovereign_coin.redeem_spread_bps = args.redeem_spread_bps;
+        sovereign_coin.spread_recipient = args.spread_recipient;
+
+        let clock = Clock::get()?;
+        emit_cpi!(FxSpreadConfiguredEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            mint_spread_bps: args.mint_spread_bps,
+            redeem_spread_bps: args.redeem_spread_bps,
+            spread_recipient: args.spread_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureInKindRedemptionArgs {
+    pub is_enabled: bool,
+    pub min_sovereign_amount: u64,
+    pub max_sovereign_amount: u64,  // 0 for no cap on a single redemption
+    pub period_duration: i64,       // 0 disables the period cap
+    pub period_limit: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureInKindRedemption<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+        constraint = !sovereign_coin.is_basket @ StablecoinError::BasketNotSupported,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + InKindRedemptionConfig::INIT_SPACE,
+        seeds = [b"in_kind_redemption", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub in_kind_redemption_config: Box<Account<'info, InKindRedemptionConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ConfigureInKindRedemption<'_> {
+    pub fn handler(ctx: Context<Self>, args: ConfigureInKindRedemptionArgs) -> Result<()> {
+        require!(
+            (args.max_sovereign_amount == 0 || args.min_sovereign_amount <= args.max_sovereign_amount)
+                && args.period_duration >= 0,
+            StablecoinError::InvalidInKindRedemptionLimits
+        );
+
+        // Running totals are kept, a new period duration applies from the next period
+        let clock = Clock::get()?;
+        let config = &mut ctx.accounts.in_kind_redemption_config;
+        config.bump = ctx.bumps.in_kind_redemption_config;
+        config.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        config.is_enabled = args.is_enabled;
+        config.min_sovereign_amount = args.min_sovereign_amount;
+        config.max_sovereign_amount = args.max_sovereign_amount;
+        config.period_duration = args.period_duration;
+        config.period_limit = args.period_limit;
+        config.updated_at = clock.unix_timestamp;
+
+        emit_cpi!(InKindRedemptionConfiguredEvent {
+            sovereign_coin: config.sovereign_coin,
+            authority: ctx.accounts.authority.key(),
+            is_enabled: args.is_enabled,
+            min_sovereign_amount: args.min_sovereign_amount,
+            max_sovereign_amount: args.max_sovereign_amount,
+            period_duration: args.period_duration,
+            period_limit: args.period_limit,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureRfqSignerArgs {
+    pub signer: Pubkey,
+    pub is_enabled: bool,
+    pub max_oracle_deviation_bps: u16, // Band around the oracle price and TWAP quotes must fall in
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureRfqSigner<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RfqQuoteSigner::INIT_SPACE,
+        seeds = [b"rfq_signer", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub rfq_signer: Box<Account<'info, RfqQuoteSigner>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ConfigureRfqSigner<'_> {
+    pub fn handler(ctx: Context<Self>, args: ConfigureRfqSignerArgs) -> Result<()> {
+        require!(
+            args.max_oracle_deviation_bps > 0 && args.max_oracle_deviation_bps <= MAX_RFQ_ORACLE_DEVIATION_BPS,
+            StablecoinError::InvalidRfqSignerConfig
+        );
+
+        // Rotating the key voids every quote the old key signed that is still open
+        let clock = Clock::get()?;
+        let rfq_signer = &mut ctx.accounts.rfq_signer;
+        rfq_signer.bump = ctx.bumps.rfq_signer;
+        rfq_signer.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        rfq_signer.signer = args.signer;
+        rfq_signer.is_enabled = args.is_enabled;
+        rfq_signer.max_oracle_deviation_bps = args.max_oracle_deviation_bps;
+        rfq_signer.updated_at = clock.unix_timestamp;
+
+        emit_cpi!(RfqSignerConfiguredEvent {
+            sovereign_coin: rfq_signer.sovereign_coin,
+            authority: ctx.accounts.authority.key(),
+            signer: args.signer,
+            is_enabled: args.is_enabled,
+            max_oracle_deviation_bps: args.max_oracle_deviation_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
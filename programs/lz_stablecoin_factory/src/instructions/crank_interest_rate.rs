@@ -0,0 +1,153 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CrankInterestRate<'info> {
+    // Anyone can run the crank, the config decides how often and how far the rate moves
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_interest_bearing @ StablecoinError::NotInterestBearing,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [b"interest_rate_config", sovereign_coin.key().as_ref()],
+        bump = interest_rate_config.bump,
+    )]
+    pub interest_rate_config: Box<Account<'info, InterestRateConfig>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+        constraint = bond_holding.mint == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Required for basket coins, constituent (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
+    #[account(
+        seeds = [b"fee_curve"],
+        bump = fee_curve.bump,
+    )]
+    pub fee_curve: Box<Account<'info, FeeCurveConfig>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+impl CrankInterestRate<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        let interest_rate_config = &ctx.accounts.interest_rate_config;
+
+        require!(
+            interest_rate_config.can_update(clock.unix_timestamp)?,
+            StablecoinError::InterestRateUpdateTooSoon
+        );
+
+        let fee_curve = &ctx.accounts.fee_curve;
+        let tax_withholding_bps = fee_curve.tax_withholding_bps_for(&ctx.accounts.sovereign_coin.target_fiat_currency);
+        let net_rate = if ctx.accounts.sovereign_coin.is_basket {
+            let bond_basket = ctx.accounts.bond_basket
+                .as_ref()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            bond_basket.weighted_interest_rate(ctx.remaining_accounts, &fee_curve.curve, tax_withholding_bps)?
+        } else {
+            let bond_rate = token_extension::read_current_interest_rate(
+                &ctx.accounts.bond_token_mint.to_account_info()
+            )?;
+            interest::calculate_sovereign_interest_rate(
+                bond_rate,
+                ctx.accounts.bond_holding.amount,
+                &fee_curve.curve,
+                tax_withholding_bps,
+            )?
+        };
+        // Holders only earn their share, the smoothing applies to what they see on the mint
+        let target_rate = ctx.accounts.sovereign_coin.applied_interest_rate(net_rate, ctx.accounts.factory.yield_share_holders)?;
+
+        let previous_rate = ctx.accounts.sovereign_coin.interest_rate;
+        let new_rate = interest::smooth_interest_rate(
+            previous_rate,
+            target_rate,
+            interest_rate_config.max_rate_step_bps,
+            interest_rate_config.rate_floor,
+            interest_rate_config.rate_ceiling,
+        )?;
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+        token_extension::update_interest_rate_signed(
+            &ctx.accounts.factory.to_account_info(),
+            factory_signer,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_2022_program,
+            new_rate,
+        )?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+        sovereign_coin.interest_rate = new_rate;
+        sovereign_coin.net_yield_rate = net_rate;
+
+        ctx.accounts.interest_rate_config.record_update(InterestRateHistoryEntry {
+            previous_rate,
+            new_rate,
+            target_rate,
+            updated_at: clock.unix_timestamp,
+            updater: ctx.accounts.keeper.key(),
+        });
+
+        emit_cpi!(InterestRateCrankedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            keeper: ctx.accounts.keeper.key(),
+            previous_rate,
+            target_rate,
+            new_rate,
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit_cpi!(SovereignCoinInterestRateUpdatedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            mint: sovereign_coin.mint,
+            old_rate: previous_rate,
+            new_rate,
+            bond_mint: sovereign_coin.bond_mint,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CrankPriceHistory<'info> {
+    // Anyone can sample the oracle, the history decides how often
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    /// CHECK: Oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+
+    /// CHECK: Quote oracle account
+    #[account(
+        constraint = Some(payment_quote_price_feed_account.key()) == factory.payment_quote_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The currency's price route address, always passed so a keeper can't leave a configured route out.
+    /// Read as a PriceRoute when one exists, its leg feeds are then the remaining accounts.
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump,
+    )]
+    pub price_route: UncheckedAccount<'info>,
+}
+
+impl CrankPriceHistory<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            ctx.accounts.price_history.can_crank(clock.unix_timestamp)?,
+            StablecoinError::PriceHistoryUpdateTooSoon
+        );
+
+        let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
+        let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
+            .map(|acc| acc.to_account_info());
+
+        let price_route = PriceRoute::load_configured(&ctx.accounts.price_route)?;
+
+        // USD coins trade at par, the feed that can move their price is USDC/USD
+        let oracle_price = match switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            price_route.as_ref().map(|route| (route, ctx.remaining_accounts)),
+            ctx.accounts.sovereign_coin.target_currency()?,
+        )? {
+            Some(price) => price,
+            None => (switchboard::get_usdc_usd_price(&base_account_info)? as i128, PRICE_PRECISION_DECIMALS as u32),
+        };
+
+        // Cranks are the oracle's own readings, they are never held against the TWAP
+        let sample = PriceSample::new(oracle_price, PriceSampleSource::Crank, &clock);
+        ctx.accounts.price_history.record(sample);
+
+        emit_cpi!(PriceHistorySampledEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            keeper: ctx.accounts.keeper.key(),
+            mantissa: sample.mantissa,
+            scale: sample.scale,
+            slot: sample.slot,
+            timestamp: sample.timestamp,
+        });
+
+        Ok(())
+    }
+}
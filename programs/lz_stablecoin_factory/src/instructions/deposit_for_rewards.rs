@@ -0,0 +1,118 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositForRewards<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.yield_mode == YieldMode::Claimable @ StablecoinError::YieldModeMismatch,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + HolderRewards::INIT_SPACE,
+        seeds = [b"holder_rewards", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub holder_rewards: Box<Account<'info, HolderRewards>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub holder_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Deposited coins sit with the coin PDA so they stay apart from the protocol's own vault
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = mint,
+        associated_token::authority = sovereign_coin,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub rewards_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl DepositForRewards<'_> {
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+
+        let clock = Clock::get()?;
+        // Yield up to now is booked against the deposits as they were
+        ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+
+        let holder_rewards = &mut ctx.accounts.holder_rewards;
+        if holder_rewards.holder == Pubkey::default() {
+            holder_rewards.bump = ctx.bumps.holder_rewards;
+            holder_rewards.sovereign_coin = ctx.accounts.sovereign_coin.key();
+            holder_rewards.holder = ctx.accounts.holder.key();
+            holder_rewards.reward_index_snapshot = ctx.accounts.sovereign_coin.reward_index;
+        }
+        holder_rewards.checkpoint(ctx.accounts.sovereign_coin.reward_index)?;
+
+        let vault_balance_before = ctx.accounts.rewards_vault.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.holder_sovereign_coin_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.rewards_vault.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        // Only what arrives after the transfer fee earns rewards
+        ctx.accounts.rewards_vault.reload()?;
+        let deposited = ctx.accounts.rewards_vault.amount.safe_sub(vault_balance_before)?;
+
+        let holder_rewards = &mut ctx.accounts.holder_rewards;
+        holder_rewards.deposited = holder_rewards.deposited.safe_add(deposited)?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.reward_deposits = sovereign_coin.reward_deposits.safe_add(deposited)?;
+
+        emit_cpi!(RewardDepositChangedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            holder: ctx.accounts.holder.key(),
+            deposited: deposited as i64,
+            holder_deposit: holder_rewards.deposited,
+            total_deposits: sovereign_coin.reward_deposits,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
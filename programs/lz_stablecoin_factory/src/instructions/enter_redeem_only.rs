@@ -0,0 +1,46 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EnterRedeemOnly<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+        constraint = sovereign_coin.is_active() @ StablecoinError::InvalidLifecycleTransition,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+}
+
+impl EnterRedeemOnly<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+
+        // Freeze new supply, holders keep the normal redemption waterfall
+        sovereign_coin.lifecycle = SovereignCoinLifecycle::RedeemOnly;
+
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinLifecycleChangedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            old_lifecycle: SovereignCoinLifecycle::Active,
+            new_lifecycle: SovereignCoinLifecycle::RedeemOnly,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -30,11 +30,11 @@ pub struct FinalizeSetup<'info> {
     )]
     pub mint: Box<InterfaceAccount<'info, Mint>>,
     
-    /// CHECK: Will be created via CPI to token metadata program
+    /// CHECK: Will be created via CPI to token metadata program, not needed for Token-2022 metadata coins
     #[account(mut)]
-    pub metadata: UncheckedAccount<'info>,
+    pub metadata: Option<UncheckedAccount<'info>>,
     
-    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -48,42 +48,7 @@ impl FinalizeSetup<'_> {
             StablecoinError::InvalidMintAuthority
         );
         
-        let name = std::str::from_utf8(
-            &sovereign_coin.name
-                .iter()
-                .take_while(|&&b| b != 0)
-                .cloned()
-                .collect::<Vec<u8>>()
-        ).unwrap_or("").to_string();
-        
-        let symbol = std::str::from_utf8(
-            &sovereign_coin.symbol
-                .iter()
-                .take_while(|&&b| b != 0)
-                .cloned()
-                .collect::<Vec<u8>>()
-        ).unwrap_or("").to_string();
-        
-        let uri = std::str::from_utf8(
-            &sovereign_coin.uri
-                .iter()
-                .take_while(|&&b| b != 0)
-                .cloned()
-                .collect::<Vec<u8>>()
-        ).unwrap_or("").to_string();
-
-        require!(
-            !name.is_empty() && name.len() <= 32,
-            StablecoinError::InvalidNameLength
-        );
-        require!(
-            !symbol.is_empty() && symbol.len() <= 8,
-            StablecoinError::InvalidSymbolLength
-        );
-        require!(
-            uri.len() <= 200,
-            StablecoinError::InvalidUriLength
-        );
+        let (name, symbol, uri) = sovereign_coin.metadata_strings()?;
         
         let initial_count = ctx.accounts.factory.total_sovereign_coins;
 
@@ -93,32 +58,43 @@ impl FinalizeSetup<'_> {
         ];
         let factory_signer = &[&factory_seeds[..]];
         
-        let cpi_program = ctx.accounts.token_metadata_program.to_account_info();
-        let cpi_accounts = CreateMetadataAccountsV3 {
-            metadata: ctx.accounts.metadata.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            mint_authority: ctx.accounts.factory.to_account_info(),
-            payer: ctx.accounts.creator.to_account_info(),
-            update_authority: ctx.accounts.factory.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            rent: ctx.accounts.rent.to_account_info(),
-        };
-        
-        create_metadata_accounts_v3(
-            CpiContext::new_with_signer(cpi_program, cpi_accounts, factory_signer),
-            DataV2 {
-                name: name.clone(),
-                symbol: symbol.clone(),
-                uri: uri.clone(),
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-                uses: None,
-            },
-            false,
-            true,
-            None,
-        )?;
+        // Token-2022 metadata coins already carry their metadata in the mint
+        if !sovereign_coin.uses_token_metadata {
+            let metadata = ctx.accounts.metadata
+                .as_ref()
+                .ok_or(StablecoinError::InvalidMetadataAccount)?;
+            let token_metadata_program = ctx.accounts.token_metadata_program
+                .as_ref()
+                .ok_or(StablecoinError::InvalidMetadataAccount)?;
+
+            let cpi_program = token_metadata_program.to_account_info();
+            let cpi_accounts = CreateMetadataAccountsV3 {
+                metadata: metadata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.factory.to_account_info(),
+                payer: ctx.accounts.creator.to_account_info(),
+                update_authority: ctx.accounts.factory.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            };
+            
+            // Mutable so the issuer can later update it through update_sovereign_coin_metadata
+            create_metadata_accounts_v3(
+                CpiContext::new_with_signer(cpi_program, cpi_accounts, factory_signer),
+                DataV2 {
+                    name: name.clone(),
+                    symbol: symbol.clone(),
+                    uri: uri.clone(),
+                    seller_fee_basis_points: 0,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                },
+                true,
+                true,
+                None,
+            )?;
+        }
         
         let factory = &mut ctx.accounts.factory;
         factory.total_sovereign_coins = factory.total_sovereign_coins
@@ -7,6 +7,7 @@ pub struct ExecuteInstantRedemption<'info> {
     pub payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -20,9 +21,29 @@ pub struct ExecuteInstantRedemption<'info> {
             &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
         ],
         bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     #[account(
         mut,
         seeds = [b"redeem_state", payer.key().as_ref(), sovereign_coin.key().as_ref()],
@@ -82,7 +103,7 @@ pub struct ExecuteInstantRedemption<'info> {
         associated_token::mint = bond_token_mint,
         associated_token::authority = factory,
         constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
-        constraint = bond_holding.amount >= redeem_state.from_bond_redemption @ StablecoinError::InsufficientBondBalance
+        constraint = sovereign_coin.is_basket || bond_holding.amount >= redeem_state.from_bond_redemption @ StablecoinError::InsufficientBondBalance
     )]
     pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -95,15 +116,39 @@ pub struct ExecuteInstantRedemption<'info> {
         constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint
     )]
     pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Basket coins draw the bond redemption from every constituent in proportion
+    /// to its holdings, (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
     
+    // Keeps the oracle price the redemption was checked at once it has gone through
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub token_2022_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-impl ExecuteInstantRedemption<'_> {
-    pub fn handler(ctx: Context<Self>) -> Result<()> {
+impl<'info> ExecuteInstantRedemption<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         let redeem_state = &ctx.accounts.redeem_state;
 
@@ -178,64 +223,102 @@ impl ExecuteInstantRedemption<'_> {
             )?;
         }
 
-        // Attempt instant bond redemption
-        let bond_issuance_number = sovereign_coin.bond_issuance_number;
-        let payment_feed_type = sovereign_coin.get_payment_feed_type()?;
-        let (bond_pda, _) = find_bond_pda(ctx.accounts.bond_token_mint.key());
-        let (issuance_pda, _) = find_issuance_pda(bond_pda, bond_issuance_number);
-        // let (payment_pda, _) = find_payment_pda(issuance_pda);
-        let (payment_feed_pda, _) = find_payment_feed_pda(payment_feed_type);
-        let (sell_liquidity_pda, _) = find_sell_liquidity_pda(bond_pda);
-        let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &ctx.accounts.usdc_token_mint.key());
-        let fee_collector_wallet_token_account = get_associated_token_address(&ETHERFUSE_FEE_COLLECTOR, &ctx.accounts.usdc_token_mint.key());
-
         let factory_seeds = &[
             b"factory".as_ref(),
             &[ctx.accounts.factory.bump],
         ];
         let factory_signer = &[&factory_seeds[..]];
 
-        let instant_redemption_ix = InstantBondRedemption {
-            user_wallet: ctx.accounts.factory.key(),
-            user_bond_token_account: ctx.accounts.bond_holding.key(),
-            user_payment_token_account: ctx.accounts.global_usdc_account.key(),
-            bond_account: bond_pda,
-            mint_account: ctx.accounts.bond_token_mint.key(),
-            issuance_account: issuance_pda,
-            payment_mint_account: ctx.accounts.usdc_token_mint.key(),
-            payment_feed_account: payment_feed_pda,
-            sell_liquidity_account: sell_liquidity_pda,
-            sell_liquidity_token_account,
-            fee_collector_wallet_token_account,
-            payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
-            payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
-            associated_token_program: spl_associated_token_account::id(),
-            token_program: spl_token::id(),
-            token2022_program: spl_token_2022::id(),
-            system_program: solana_program::system_program::id(),
-        }
-        .instruction(InstantBondRedemptionInstructionArgs {
-            amount: redeem_state.from_bond_redemption,
-        });
+        // Attempt instant bond redemption
+        if sovereign_coin.is_basket {
+            let bond_basket = ctx.accounts.bond_basket
+                .as_mut()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            let allocations = bond_basket.split_by_holdings(redeem_state.from_bond_redemption, sovereign_coin.bond_amount)?;
+            let constituent_accounts = bond_basket.constituent_accounts(ctx.remaining_accounts)?;
 
-        let instant_result = solana_program::program::invoke_signed(
-            &instant_redemption_ix,
-            &[
-                ctx.accounts.factory.to_account_info(),
-                ctx.accounts.bond_holding.to_account_info(),
-                ctx.accounts.global_usdc_account.to_account_info(),
-                ctx.accounts.bond_token_mint.to_account_info(),
-                ctx.accounts.usdc_token_mint.to_account_info(),
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.token_2022_program.to_account_info(),
-                ctx.accounts.associated_token_program.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-            ],
-            factory_signer,
-        );
+            let programs = StablebondPrograms {
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                token_program: &ctx.accounts.token_program.to_account_info(),
+                token_2022_program: &ctx.accounts.token_2022_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            };
+
+            for (i, (bond_mint, bond_holding)) in constituent_accounts.into_iter().enumerate() {
+                if allocations[i] == 0 {
+                    continue;
+                }
+
+                // If any leg fails the whole redemption fails, the client can fall back to NFT redemption
+                instant_redeem_bond_signed(
+                    &ctx.accounts.factory,
+                    factory_signer,
+                    bond_holding,
+                    &ctx.accounts.global_usdc_account.to_account_info(),
+                    bond_mint,
+                    &ctx.accounts.usdc_token_mint.to_account_info(),
+                    &programs,
+                    bond_basket.constituents[i].get_payment_feed_type()?,
+                    bond_basket.constituents[i].bond_issuance_number,
+                    allocations[i],
+                )?;
+
+                let constituent = &mut bond_basket.constituents[i];
+                constituent.bond_amount = constituent.bond_amount.safe_sub(allocations[i])?;
+            }
+        } else {
+            let bond_issuance_number = sovereign_coin.bond_issuance_number;
+            let payment_feed_type = sovereign_coin.get_payment_feed_type()?;
+            let (bond_pda, _) = find_bond_pda(ctx.accounts.bond_token_mint.key());
+            let (issuance_pda, _) = find_issuance_pda(bond_pda, bond_issuance_number);
+            // let (payment_pda, _) = find_payment_pda(issuance_pda);
+            let (payment_feed_pda, _) = find_payment_feed_pda(payment_feed_type);
+            let (sell_liquidity_pda, _) = find_sell_liquidity_pda(bond_pda);
+            let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &ctx.accounts.usdc_token_mint.key());
+            let fee_collector_wallet_token_account = get_associated_token_address(&ETHERFUSE_FEE_COLLECTOR, &ctx.accounts.usdc_token_mint.key());
+
+            let instant_redemption_ix = InstantBondRedemption {
+                user_wallet: ctx.accounts.factory.key(),
+                user_bond_token_account: ctx.accounts.bond_holding.key(),
+                user_payment_token_account: ctx.accounts.global_usdc_account.key(),
+                bond_account: bond_pda,
+                mint_account: ctx.accounts.bond_token_mint.key(),
+                issuance_account: issuance_pda,
+                payment_mint_account: ctx.accounts.usdc_token_mint.key(),
+                payment_feed_account: payment_feed_pda,
+                sell_liquidity_account: sell_liquidity_pda,
+                sell_liquidity_token_account,
+                fee_collector_wallet_token_account,
+                payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
+                payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
+                associated_token_program: spl_associated_token_account::id(),
+                token_program: spl_token::id(),
+                token2022_program: spl_token_2022::id(),
+                system_program: solana_program::system_program::id(),
+            }
+            .instruction(InstantBondRedemptionInstructionArgs {
+                amount: redeem_state.from_bond_redemption,
+            });
 
-        // If instant redemption fails, return error to let client try NFT redemption
-        require!(instant_result.is_ok(), StablecoinError::InstantRedemptionFailed);
+            let instant_result = solana_program::program::invoke_signed(
+                &instant_redemption_ix,
+                &[
+                    ctx.accounts.factory.to_account_info(),
+                    ctx.accounts.bond_holding.to_account_info(),
+                    ctx.accounts.global_usdc_account.to_account_info(),
+                    ctx.accounts.bond_token_mint.to_account_info(),
+                    ctx.accounts.usdc_token_mint.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+                factory_signer,
+            );
+
+            // If instant redemption fails, return error to let client try NFT redemption
+            require!(instant_result.is_ok(), StablecoinError::InstantRedemptionFailed);
+        }
 
         // Transfer bond redemption proceeds to user
         ctx.accounts.global_usdc_account.reload()?;
@@ -260,9 +343,35 @@ impl ExecuteInstantRedemption<'_> {
         }
 
         // Update sovereign coin state
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
         sovereign_coin.total_supply = sovereign_coin.total_supply.safe_sub(redeem_state.sovereign_amount)?;
         sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_sub(redeem_state.from_usdc_reserve)?;
         sovereign_coin.bond_amount = sovereign_coin.bond_amount.safe_sub(redeem_state.from_bond_redemption)?;
+        let spread_booked = sovereign_coin.book_redeem_spread(redeem_state.spread_amount)?;
+
+        ctx.accounts.factory.decrease_total_supply(redeem_state.sovereign_amount)?;
+        ctx.accounts.factory.decrease_total_backing(redeem_state.from_usdc_reserve.safe_add(redeem_state.from_bond_redemption)?)?;
+        ctx.accounts.currency_stats.record_redeem(
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve,
+            redeem_state.from_bond_redemption,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_redeem(
+            RedemptionTypeState::InstantBondRedemption,
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve
+                .safe_add(redeem_state.from_protocol_vault)?
+                .safe_add(actual_usdc_received)?,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.price_history.record_trade_price(
+            (redeem_state.oracle_price_mantissa, redeem_state.oracle_price_scale),
+            PriceSampleSource::Redeem,
+            &clock,
+        );
 
         // Verification
         ctx.accounts.user_sovereign_coin_account.reload()?;
@@ -292,6 +401,18 @@ impl ExecuteInstantRedemption<'_> {
             redemption_type: RedemptionTypeState::InstantBondRedemption,
         });
 
+        emit_cpi!(FxSpreadAppliedEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            payer: ctx.accounts.payer.key(),
+            is_mint: false,
+            oracle_rate: redeem_state.oracle_rate,
+            applied_rate: redeem_state.applied_rate,
+            spread_bps: redeem_state.spread_bps,
+            spread_amount: spread_booked,
+            spread_recipient: ctx.accounts.sovereign_coin.spread_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
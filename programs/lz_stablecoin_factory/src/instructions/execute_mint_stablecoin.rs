@@ -7,6 +7,7 @@ pub struct ExecuteMintSovereignCoin<'info> {
     pub payer: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -20,9 +21,29 @@ pub struct ExecuteMintSovereignCoin<'info> {
             &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
         ],
         bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     #[account(
         mut,
         seeds = [b"mint_state", payer.key().as_ref(), sovereign_coin.key().as_ref()],
@@ -97,6 +118,15 @@ pub struct ExecuteMintSovereignCoin<'info> {
     )]
     pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Basket coins split the bond purchase across these constituents, whose
+    /// (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
     /// CHECK: Oracle account
     #[account(
         constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
@@ -105,6 +135,14 @@ pub struct ExecuteMintSovereignCoin<'info> {
 
     /// CHECK: Quote oracle account
     pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Keeps the oracle price the mint was checked at once it has gone through
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
     
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -112,8 +150,15 @@ pub struct ExecuteMintSovereignCoin<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-impl ExecuteMintSovereignCoin<'_> {
-    pub fn handler(ctx: Context<Self>) -> Result<()> {
+impl<'info> ExecuteMintSovereignCoin<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         let mint_state = &ctx.accounts.mint_state;
         let previous_balance = ctx.accounts.user_sovereign_coin_account.amount;
@@ -149,8 +194,9 @@ impl ExecuteMintSovereignCoin<'_> {
             )?;
         }
 
-        // Transfer reserve amount to global USDC reserve
-        if mint_state.reserve_amount > 0 {
+        // Transfer reserve amount to global USDC reserve, the spread is held there for its recipient
+        let reserve_transfer = mint_state.reserve_amount.safe_add(mint_state.spread_amount)?;
+        if reserve_transfer > 0 {
             token_interface::transfer_checked(
                 CpiContext::new(
                     ctx.accounts.token_program.to_account_info(),
@@ -161,7 +207,7 @@ impl ExecuteMintSovereignCoin<'_> {
                         authority: ctx.accounts.payer.to_account_info(),
                     },
                 ),
-                mint_state.reserve_amount,
+                reserve_transfer,
                 ctx.accounts.usdc_mint.decimals,
             )?;
         }
@@ -183,57 +229,100 @@ impl ExecuteMintSovereignCoin<'_> {
             )?;
         }
 
-        // (These would be set immediately after the sovereign coin is initialized)
-        let bond_issuance_number = sovereign_coin.bond_issuance_number;
-        let payment_feed_type = sovereign_coin.get_payment_feed_type()?;
-        let (bond_pda, _bond_bump) = find_bond_pda(ctx.accounts.bond_token_mint.key());
-        let (issuance_pda, _issuance_bump) = find_issuance_pda(bond_pda, bond_issuance_number);
-        let (payment_pda, _payment_bump) = find_payment_pda(issuance_pda);
-        let (payment_feed_pda, _payment_feed_bump) = find_payment_feed_pda(payment_feed_type);
-        let (kyc_pda, _kyc_bump) = find_kyc_pda(ctx.accounts.factory.key()); 
+        if sovereign_coin.is_basket {
+            let bond_basket = ctx.accounts.bond_basket
+                .as_mut()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            let allocations = bond_basket.split_by_weight(mint_state.bond_amount)?;
+            let constituent_accounts = bond_basket.constituent_accounts(ctx.remaining_accounts)?;
+
+            let programs = StablebondPrograms {
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                token_program: &ctx.accounts.token_program.to_account_info(),
+                token_2022_program: &ctx.accounts.token_2022_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            };
+
+            for (i, (bond_mint, bond_holding)) in constituent_accounts.into_iter().enumerate() {
+                if allocations[i] == 0 {
+                    continue;
+                }
+
+                // Constituents track bond tokens, so book what the purchase actually delivered
+                let holding_before = InterfaceAccount::<TokenAccount>::try_from(bond_holding)?.amount;
+                purchase_bond_signed(
+                    &ctx.accounts.factory,
+                    factory_signer,
+                    bond_holding,
+                    &ctx.accounts.global_usdc_account.to_account_info(),
+                    bond_mint,
+                    &ctx.accounts.usdc_mint.to_account_info(),
+                    &programs,
+                    bond_basket.constituents[i].get_payment_feed_type()?,
+                    bond_basket.constituents[i].bond_issuance_number,
+                    allocations[i],
+                )?;
+                let bonds_bought = InterfaceAccount::<TokenAccount>::try_from(bond_holding)?
+                    .amount
+                    .safe_sub(holding_before)?;
+
+                let constituent = &mut bond_basket.constituents[i];
+                constituent.bond_amount = constituent.bond_amount.safe_add(bonds_bought)?;
+            }
+        } else {
+            // (These would be set immediately after the sovereign coin is initialized)
+            let bond_issuance_number = sovereign_coin.bond_issuance_number;
+            let payment_feed_type = sovereign_coin.get_payment_feed_type()?;
+            let (bond_pda, _bond_bump) = find_bond_pda(ctx.accounts.bond_token_mint.key());
+            let (issuance_pda, _issuance_bump) = find_issuance_pda(bond_pda, bond_issuance_number);
+            let (payment_pda, _payment_bump) = find_payment_pda(issuance_pda);
+            let (payment_feed_pda, _payment_feed_bump) = find_payment_feed_pda(payment_feed_type);
+            let (kyc_pda, _kyc_bump) = find_kyc_pda(ctx.accounts.factory.key()); 
         
-        // Get associated token addresses - use the get ata with program id instead
-        let payment_token_account = get_associated_token_address(&payment_pda, &ctx.accounts.usdc_mint.key());
-
-        // Create purchase bond instruction
-        let purchase_bond_ix = PurchaseBondV2 {
-            user_wallet: ctx.accounts.factory.key(), 
-            user_token_account: ctx.accounts.bond_holding.key(), 
-            user_payment_token_account: ctx.accounts.global_usdc_account.key(),
-            bond_account: bond_pda,
-            issuance_account: issuance_pda,
-            payment_account: payment_pda,
-            payment_token_account,
-            kyc_account: kyc_pda,
-            mint_account: ctx.accounts.bond_token_mint.key(),
-            payment_mint_account: ctx.accounts.usdc_mint.key(),
-            payment_feed_account: payment_feed_pda,
-            payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
-            payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
-            associated_token_program: spl_associated_token_account::id(),
-            token_program: spl_token::id(),
-            token2022_program: spl_token_2022::id(),
-            system_program: solana_program::system_program::id(),
-        }
-        .instruction(PurchaseBondV2InstructionArgs {
-            amount: mint_state.bond_amount, 
-        });
+            // Get associated token addresses - use the get ata with program id instead
+            let payment_token_account = get_associated_token_address(&payment_pda, &ctx.accounts.usdc_mint.key());
 
-        solana_program::program::invoke_signed(
-            &purchase_bond_ix,
-            &[
-                ctx.accounts.factory.to_account_info(),
-                ctx.accounts.bond_holding.to_account_info(),
-                ctx.accounts.global_usdc_account.to_account_info(),
-                ctx.accounts.bond_token_mint.to_account_info(),
-                ctx.accounts.usdc_mint.to_account_info(),
-                ctx.accounts.system_program.to_account_info(),
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.token_2022_program.to_account_info(),
-                ctx.accounts.associated_token_program.to_account_info(),
-            ],
-            factory_signer,
-        )?;
+            // Create purchase bond instruction
+            let purchase_bond_ix = PurchaseBondV2 {
+                user_wallet: ctx.accounts.factory.key(), 
+                user_token_account: ctx.accounts.bond_holding.key(), 
+                user_payment_token_account: ctx.accounts.global_usdc_account.key(),
+                bond_account: bond_pda,
+                issuance_account: issuance_pda,
+                payment_account: payment_pda,
+                payment_token_account,
+                kyc_account: kyc_pda,
+                mint_account: ctx.accounts.bond_token_mint.key(),
+                payment_mint_account: ctx.accounts.usdc_mint.key(),
+                payment_feed_account: payment_feed_pda,
+                payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
+                payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
+                associated_token_program: spl_associated_token_account::id(),
+                token_program: spl_token::id(),
+                token2022_program: spl_token_2022::id(),
+                system_program: solana_program::system_program::id(),
+            }
+            .instruction(PurchaseBondV2InstructionArgs {
+                amount: mint_state.bond_amount, 
+            });
+
+            solana_program::program::invoke_signed(
+                &purchase_bond_ix,
+                &[
+                    ctx.accounts.factory.to_account_info(),
+                    ctx.accounts.bond_holding.to_account_info(),
+                    ctx.accounts.global_usdc_account.to_account_info(),
+                    ctx.accounts.bond_token_mint.to_account_info(),
+                    ctx.accounts.usdc_mint.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                ],
+                factory_signer,
+            )?;
+
+        }
 
         token_interface::mint_to(
             CpiContext::new_with_signer(
@@ -248,12 +337,37 @@ impl ExecuteMintSovereignCoin<'_> {
             mint_state.sovereign_amount,
         )?;
 
+        // Yield claims accrue on the bond balance before this mint changes it
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
         sovereign_coin.total_supply = sovereign_coin.total_supply
             .safe_add(mint_state.sovereign_amount)?;
         sovereign_coin.usdc_amount = sovereign_coin.usdc_amount
             .safe_add(mint_state.reserve_amount)?;
         sovereign_coin.bond_amount = sovereign_coin.bond_amount
             .safe_add(mint_state.bond_amount)?; 
+        sovereign_coin.spread_revenue_accrued = sovereign_coin.spread_revenue_accrued
+            .safe_add(mint_state.spread_amount)?;
+
+        ctx.accounts.factory.increase_total_supply(mint_state.sovereign_amount)?;
+        ctx.accounts.factory.increase_total_backing(mint_state.reserve_amount.safe_add(mint_state.bond_amount)?)?;
+        ctx.accounts.currency_stats.record_mint(
+            mint_state.sovereign_amount,
+            mint_state.reserve_amount,
+            mint_state.bond_amount,
+            mint_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_mint(
+            mint_state.sovereign_amount,
+            mint_state.usdc_amount,
+            mint_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.price_history.record_trade_price(
+            (mint_state.oracle_price_mantissa, mint_state.oracle_price_scale),
+            PriceSampleSource::Mint,
+            &clock,
+        );
 
         ctx.accounts.user_sovereign_coin_account.reload()?;
         require!(
@@ -273,6 +387,18 @@ impl ExecuteMintSovereignCoin<'_> {
             timestamp: clock.unix_timestamp,
         });
 
+        emit_cpi!(FxSpreadAppliedEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            payer: ctx.accounts.payer.key(),
+            is_mint: true,
+            oracle_rate: mint_state.oracle_rate,
+            applied_rate: mint_state.applied_rate,
+            spread_bps: mint_state.spread_bps,
+            spread_amount: mint_state.spread_amount,
+            spread_recipient: ctx.accounts.sovereign_coin.spread_recipient,
+            timestamp: clock.unix_timestamp,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -96,7 +96,7 @@ pub struct ExecuteRedeemFromBonds<'info> {
         associated_token::mint = bond_token_mint,
         associated_token::authority = factory,
         constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
-        constraint = bond_holding.amount >= redeem_state.from_bond_redemption @ StablecoinError::InsufficientBondBalance
+        constraint = sovereign_coin.is_basket || bond_holding.amount >= redeem_state.from_bond_redemption @ StablecoinError::InsufficientBondBalance
     )]
     pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
     
@@ -146,6 +146,15 @@ pub struct ExecuteRedeemFromBonds<'info> {
         constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint
     )]
     pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Basket coins sell the same share of every constituent, whose
+    /// (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
     
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -155,8 +164,8 @@ pub struct ExecuteRedeemFromBonds<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-impl ExecuteRedeemFromBonds<'_> {
-    pub fn handler(ctx: Context<Self>) -> Result<()> {
+impl<'info> ExecuteRedeemFromBonds<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         let redeem_state = &ctx.accounts.redeem_state;
 
@@ -228,81 +237,53 @@ impl ExecuteRedeemFromBonds<'_> {
         }
 
         let mut redemption_type = RedemptionTypeState::InstantBondRedemption;
-        let bond_issuance_number = sovereign_coin.bond_issuance_number;
-        let payment_feed_type = sovereign_coin.payment_feed_type.clone();
-        let (bond_pda, _bond_bump) = find_bond_pda(ctx.accounts.bond_token_mint.key());
-        let (issuance_pda, _issuance_bump) = find_issuance_pda(bond_pda, bond_issuance_number);
-        let (payment_pda, _payment_bump) = find_payment_pda(issuance_pda);
-        let (payment_feed_pda, _payment_feed_bump) = find_payment_feed_pda(payment_feed_type);
-        let (sell_liquidity_pda, _sell_liquidity_bump) = find_sell_liquidity_pda(bond_pda);
-        let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &ctx.accounts.usdc_token_mint.key());
-        let fee_collector_wallet_token_account =   get_associated_token_address(ETHERFUSE_FEE_COLLECTOR, &ctx.accounts.usdc_token_mint.key());
-        
-        let instant_redemption_ix = InstantBondRedemption {
-            user_wallet: ctx.accounts.factory.key(), 
-            user_bond_token_account: ctx.accounts.bond_holding.key(),
-            user_payment_token_account: ctx.accounts.global_usdc_account.key(),
-            bond_account: bond_pda,
-            mint_account: ctx.accounts.bond_token_mint.key(),
-            issuance_account: issuance_pda,
-            payment_mint_account: ctx.accounts.usdc_token_mint.key(),
-            payment_feed_account: payment_feed_pda,
-            sell_liquidity_account: sell_liquidity_pda,
-            sell_liquidity_token_account,
-            fee_collector_wallet_token_account, 
-            payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
-            payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
-            associated_token_program: spl_associated_token_account::id(),
-            token_program: spl_token::id(),
-            token2022_program: spl_token_2022::id(),
-            system_program: solana_program::system_program::id(),
-        }
-        .instruction(InstantBondRedemptionInstructionArgs {
-            amount: redeem_state.from_bond_redemption,
-        });
-    
-            // Using factory signer for bond redemption
+        if sovereign_coin.is_basket {
+            // Every constituent gives up the same share, there is no NFT fallback across several bonds
             let factory_seeds = &[
                 b"factory".as_ref(),
                 &[ctx.accounts.factory.bump],
             ];
             let factory_signer = &[&factory_seeds[..]];
-        
-            let instant_result = solana_program::program::invoke_signed(
-                &instant_redemption_ix,
-                &[
-                    ctx.accounts.factory.to_account_info(),
-                    ctx.accounts.bond_holding.to_account_info(),
-                    ctx.accounts.global_usdc_account.to_account_info(),
-                    ctx.accounts.bond_token_mint.to_account_info(),
-                    ctx.accounts.sovereign_coin_protocol_vault.to_account_info(),
-                    ctx.accounts.usdc_token_mint.to_account_info(),
-                    ctx.accounts.token_program.to_account_info(),
-                    ctx.accounts.token_2022_program.to_account_info(),
-                    ctx.accounts.associated_token_program.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                    
-                ],
-                factory_signer, 
-            );
+            let bond_basket = ctx.accounts.bond_basket
+                .as_mut()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            let allocations = bond_basket.split_by_holdings(redeem_state.from_bond_redemption, sovereign_coin.bond_amount)?;
+            let constituent_accounts = bond_basket.constituent_accounts(ctx.remaining_accounts)?;
+
+            let programs = StablebondPrograms {
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                token_program: &ctx.accounts.token_program.to_account_info(),
+                token_2022_program: &ctx.accounts.token_2022_program.to_account_info(),
+                associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+            };
+
+            for (i, (bond_mint, bond_holding)) in constituent_accounts.into_iter().enumerate() {
+                if allocations[i] == 0 {
+                    continue;
+                }
+
+                instant_redeem_bond_signed(
+                    &ctx.accounts.factory,
+                    factory_signer,
+                    bond_holding,
+                    &ctx.accounts.global_usdc_account.to_account_info(),
+                    bond_mint,
+                    &ctx.accounts.usdc_token_mint.to_account_info(),
+                    &programs,
+                    bond_basket.constituents[i].get_payment_feed_type()?,
+                    bond_basket.constituents[i].bond_issuance_number,
+                    allocations[i],
+                )?;
+
+                let constituent = &mut bond_basket.constituents[i];
+                constituent.bond_amount = constituent.bond_amount.safe_sub(allocations[i])?;
+            }
 
-            if instant_result.is_ok() {
-            // Reload to get updated balance
             ctx.accounts.global_usdc_account.reload()?;
-            let global_usdc_balance_after = ctx.accounts.global_usdc_account.amount;
-            
-            // Calculate actual USDC received from bond redemption
-            let actual_usdc_received = global_usdc_balance_after
+            let actual_usdc_received = ctx.accounts.global_usdc_account.amount
                 .safe_sub(global_usdc_balance_before)?;
-            
-            if actual_usdc_received > 0 {
-                let factory_seeds = &[
-                    b"factory".as_ref(),
-                    &[ctx.accounts.factory.bump],
-                ];
-                let factory_signer = &[&factory_seeds[..]];
 
-                // Transfer the actual amount received to user
+            if actual_usdc_received > 0 {
                 token_interface::transfer_checked(
                     CpiContext::new_with_signer(
                         ctx.accounts.token_program.to_account_info(),
@@ -318,89 +299,181 @@ impl ExecuteRedeemFromBonds<'_> {
                     ctx.accounts.usdc_token_mint.decimals,
                 )?;
             }
-        }
-
-        if instant_result.is_err() {
-            require!(
-                ctx.accounts.nft_token_account.is_some(),
-                StablecoinError::NFTTokenAccountRequired
-            );
-
-            redemption_type = RedemptionTypeState::NFTBondRedemption;
-            let (nft_issuance_vault_pda, _nft_issuance_vault_bump) = find_nft_issuance_vault_pda(ctx.accounts.nft_token_mint.key());
-            let nft_issuance_vault_token_account = get_associated_token_address(&nft_issuance_vault_pda, &ctx.accounts.nft_token_mint.key());
-            let (payout_pda, _payout_bump) = find_payout_pda(issuance_pda);
-            let payout_token_account = get_associated_token_address(&payout_pda, &ctx.accounts.usdc_token_mint.key());
-            let (nft_metadata_account, _nft_metadata_account_bump) = MetadataMpl::find_pda(&ctx.accounts.nft_token_mint.key());
-            let (nft_master_edition_account, _nft_master_edition_account_bump) = MasterEditionMpl::find_pda(&ctx.accounts.nft_token_mint.key());
-            let (nft_collection_metadata_account, _nft_collection_metadata_account_bump) = MetadataMpl::find_pda(&ctx.accounts.nft_collection_mint.key());
-
-
-            let redeem_bond_ix = RedeemBond {
+        } else {
+            let bond_issuance_number = sovereign_coin.bond_issuance_number;
+            let payment_feed_type = sovereign_coin.payment_feed_type.clone();
+            let (bond_pda, _bond_bump) = find_bond_pda(ctx.accounts.bond_token_mint.key());
+            let (issuance_pda, _issuance_bump) = find_issuance_pda(bond_pda, bond_issuance_number);
+            let (payment_pda, _payment_bump) = find_payment_pda(issuance_pda);
+            let (payment_feed_pda, _payment_feed_bump) = find_payment_feed_pda(payment_feed_type);
+            let (sell_liquidity_pda, _sell_liquidity_bump) = find_sell_liquidity_pda(bond_pda);
+            let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &ctx.accounts.usdc_token_mint.key());
+            let fee_collector_wallet_token_account =   get_associated_token_address(ETHERFUSE_FEE_COLLECTOR, &ctx.accounts.usdc_token_mint.key());
+        
+            let instant_redemption_ix = InstantBondRedemption {
                 user_wallet: ctx.accounts.factory.key(), 
+                user_bond_token_account: ctx.accounts.bond_holding.key(),
+                user_payment_token_account: ctx.accounts.global_usdc_account.key(),
                 bond_account: bond_pda,
                 mint_account: ctx.accounts.bond_token_mint.key(),
                 issuance_account: issuance_pda,
-                user_nft_token_account: ctx.accounts.nft_token_account.as_ref().unwrap().key(),
-                user_payment_token_account: ctx.accounts.global_usdc_account.key(), 
                 payment_mint_account: ctx.accounts.usdc_token_mint.key(),
                 payment_feed_account: payment_feed_pda,
-                nft_mint_account: ctx.accounts.nft_token_mint.key(),
-                nft_metadata_account,
-                nft_master_edition_account,
-                nft_collection_metadata_account,
-                nft_issuance_vault_account: nft_issuance_vault_pda,
-                nft_issuance_vault_token_account,
-                payout_account: payout_pda,
-                payout_token_account,
+                sell_liquidity_account: sell_liquidity_pda,
+                sell_liquidity_token_account,
+                fee_collector_wallet_token_account, 
+                payment_base_price_feed_account: ctx.accounts.factory.payment_base_price_feed_account,
+                payment_quote_price_feed_account: ctx.accounts.factory.payment_quote_price_feed_account,
+                associated_token_program: spl_associated_token_account::id(),
+                token_program: spl_token::id(),
                 token2022_program: spl_token_2022::id(),
-                associated_token_program: ctx.accounts.associated_token_program.key(),
-                token_program: ctx.accounts.token_program.key(),
-                metadata_program: ctx.accounts.metadata_program.key(),
-                system_program: ctx.accounts.system_program.key(),
+                system_program: solana_program::system_program::id(),
             }
-            .instruction();
-
-                let nft_result = solana_program::program::invoke_signed(
-                    &redeem_bond_ix,
+            .instruction(InstantBondRedemptionInstructionArgs {
+                amount: redeem_state.from_bond_redemption,
+            });
+    
+                // Using factory signer for bond redemption
+                let factory_seeds = &[
+                    b"factory".as_ref(),
+                    &[ctx.accounts.factory.bump],
+                ];
+                let factory_signer = &[&factory_seeds[..]];
+        
+                let instant_result = solana_program::program::invoke_signed(
+                    &instant_redemption_ix,
                     &[
-                        ctx.accounts.factory.to_account_info(), 
+                        ctx.accounts.factory.to_account_info(),
                         ctx.accounts.bond_holding.to_account_info(),
-                        ctx.accounts.bond_token_mint.to_account_info(),
-                        ctx.accounts.nft_token_account.as_ref().unwrap().to_account_info(),
                         ctx.accounts.global_usdc_account.to_account_info(),
-                        ctx.accounts.usdc_token_mint.to_account_info(),
-                        ctx.accounts.nft_token_mint.to_account_info(),
+                        ctx.accounts.bond_token_mint.to_account_info(),
                         ctx.accounts.sovereign_coin_protocol_vault.to_account_info(),
+                        ctx.accounts.usdc_token_mint.to_account_info(),
                         ctx.accounts.token_program.to_account_info(),
                         ctx.accounts.token_2022_program.to_account_info(),
                         ctx.accounts.associated_token_program.to_account_info(),
-                        ctx.accounts.metadata_program.to_account_info(),
                         ctx.accounts.system_program.to_account_info(),
-                        // Add metadata and master edition accounts
+                    
                     ],
                     factory_signer, 
                 );
 
-                if nft_result.is_ok() {
-                    token_interface::transfer(
+                if instant_result.is_ok() {
+                // Reload to get updated balance
+                ctx.accounts.global_usdc_account.reload()?;
+                let global_usdc_balance_after = ctx.accounts.global_usdc_account.amount;
+            
+                // Calculate actual USDC received from bond redemption
+                let actual_usdc_received = global_usdc_balance_after
+                    .safe_sub(global_usdc_balance_before)?;
+            
+                if actual_usdc_received > 0 {
+                    let factory_seeds = &[
+                        b"factory".as_ref(),
+                        &[ctx.accounts.factory.bump],
+                    ];
+                    let factory_signer = &[&factory_seeds[..]];
+
+                    // Transfer the actual amount received to user
+                    token_interface::transfer_checked(
                         CpiContext::new_with_signer(
                             ctx.accounts.token_program.to_account_info(),
-                            Transfer {
-                                from: ctx.accounts.nft_token_account.as_ref().unwrap().to_account_info(),
-                                to: ctx.accounts.user_nft_account.as_ref().unwrap().to_account_info(),
+                            TransferChecked {
+                                from: ctx.accounts.global_usdc_account.to_account_info(),
+                                mint: ctx.accounts.usdc_token_mint.to_account_info(),
+                                to: ctx.accounts.user_usdc_token_account.to_account_info(),
                                 authority: ctx.accounts.factory.to_account_info(),
                             },
                             factory_signer,
                         ),
-                        1, 
+                        actual_usdc_received,
+                        ctx.accounts.usdc_token_mint.decimals,
                     )?;
                 }
-                if nft_result.is_err() {
-                    return Err(error!(StablecoinError::NFTRedemptionFailed));
+            }
+
+            if instant_result.is_err() {
+                require!(
+                    ctx.accounts.nft_token_account.is_some(),
+                    StablecoinError::NFTTokenAccountRequired
+                );
+
+                redemption_type = RedemptionTypeState::NFTBondRedemption;
+                let (nft_issuance_vault_pda, _nft_issuance_vault_bump) = find_nft_issuance_vault_pda(ctx.accounts.nft_token_mint.key());
+                let nft_issuance_vault_token_account = get_associated_token_address(&nft_issuance_vault_pda, &ctx.accounts.nft_token_mint.key());
+                let (payout_pda, _payout_bump) = find_payout_pda(issuance_pda);
+                let payout_token_account = get_associated_token_address(&payout_pda, &ctx.accounts.usdc_token_mint.key());
+                let (nft_metadata_account, _nft_metadata_account_bump) = MetadataMpl::find_pda(&ctx.accounts.nft_token_mint.key());
+                let (nft_master_edition_account, _nft_master_edition_account_bump) = MasterEditionMpl::find_pda(&ctx.accounts.nft_token_mint.key());
+                let (nft_collection_metadata_account, _nft_collection_metadata_account_bump) = MetadataMpl::find_pda(&ctx.accounts.nft_collection_mint.key());
+
+
+                let redeem_bond_ix = RedeemBond {
+                    user_wallet: ctx.accounts.factory.key(), 
+                    bond_account: bond_pda,
+                    mint_account: ctx.accounts.bond_token_mint.key(),
+                    issuance_account: issuance_pda,
+                    user_nft_token_account: ctx.accounts.nft_token_account.as_ref().unwrap().key(),
+                    user_payment_token_account: ctx.accounts.global_usdc_account.key(), 
+                    payment_mint_account: ctx.accounts.usdc_token_mint.key(),
+                    payment_feed_account: payment_feed_pda,
+                    nft_mint_account: ctx.accounts.nft_token_mint.key(),
+                    nft_metadata_account,
+                    nft_master_edition_account,
+                    nft_collection_metadata_account,
+                    nft_issuance_vault_account: nft_issuance_vault_pda,
+                    nft_issuance_vault_token_account,
+                    payout_account: payout_pda,
+                    payout_token_account,
+                    token2022_program: spl_token_2022::id(),
+                    associated_token_program: ctx.accounts.associated_token_program.key(),
+                    token_program: ctx.accounts.token_program.key(),
+                    metadata_program: ctx.accounts.metadata_program.key(),
+                    system_program: ctx.accounts.system_program.key(),
                 }
+                .instruction();
+
+                    let nft_result = solana_program::program::invoke_signed(
+                        &redeem_bond_ix,
+                        &[
+                            ctx.accounts.factory.to_account_info(), 
+                            ctx.accounts.bond_holding.to_account_info(),
+                            ctx.accounts.bond_token_mint.to_account_info(),
+                            ctx.accounts.nft_token_account.as_ref().unwrap().to_account_info(),
+                            ctx.accounts.global_usdc_account.to_account_info(),
+                            ctx.accounts.usdc_token_mint.to_account_info(),
+                            ctx.accounts.nft_token_mint.to_account_info(),
+                            ctx.accounts.sovereign_coin_protocol_vault.to_account_info(),
+                            ctx.accounts.token_program.to_account_info(),
+                            ctx.accounts.token_2022_program.to_account_info(),
+                            ctx.accounts.associated_token_program.to_account_info(),
+                            ctx.accounts.metadata_program.to_account_info(),
+                            ctx.accounts.system_program.to_account_info(),
+                            // Add metadata and master edition accounts
+                        ],
+                        factory_signer, 
+                    );
+
+                    if nft_result.is_ok() {
+                        token_interface::transfer(
+                            CpiContext::new_with_signer(
+                                ctx.accounts.token_program.to_account_info(),
+                                Transfer {
+                                    from: ctx.accounts.nft_token_account.as_ref().unwrap().to_account_info(),
+                                    to: ctx.accounts.user_nft_account.as_ref().unwrap().to_account_info(),
+                                    authority: ctx.accounts.factory.to_account_info(),
+                                },
+                                factory_signer,
+                            ),
+                            1, 
+                        )?;
+                    }
+                    if nft_result.is_err() {
+                        return Err(error!(StablecoinError::NFTRedemptionFailed));
+                    }
+            }
         }
-    
+
         sovereign_coin.total_supply = sovereign_coin.total_supply
             .safe_sub(redeem_state.sovereign_amount)?;
         sovereign_coin.usdc_amount = sovereign_coin.usdc_amount
@@ -7,6 +7,7 @@ pub struct ExecuteRedeemFromFiat<'info> {
     pub payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -20,9 +21,29 @@ pub struct ExecuteRedeemFromFiat<'info> {
             &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
         ],
         bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     // Load our state from the previous instruction
     #[account(
         mut,
@@ -69,6 +90,14 @@ pub struct ExecuteRedeemFromFiat<'info> {
     )]
     pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
     
+    // Keeps the oracle price the redemption was checked at once it has gone through
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Interface<'info, TokenInterface>,
     pub token_2022_program: Program<'info, Token2022>,
@@ -76,6 +105,13 @@ pub struct ExecuteRedeemFromFiat<'info> {
 
 impl ExecuteRedeemFromFiat<'_> {
     pub fn handler(ctx: Context<Self>) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let sovereign_coin = &mut ctx.accounts.sovereign_coin;
         let redeem_state = &ctx.accounts.redeem_state;
         
@@ -114,8 +150,31 @@ impl ExecuteRedeemFromFiat<'_> {
             .safe_sub(redeem_state.sovereign_amount)?; 
         sovereign_coin.usdc_amount = sovereign_coin.usdc_amount
             .safe_sub(redeem_state.from_usdc_reserve)?;
+        let spread_booked = sovereign_coin.book_redeem_spread(redeem_state.spread_amount)?;
     
         let clock = Clock::get()?;
+        ctx.accounts.factory.decrease_total_supply(redeem_state.sovereign_amount)?;
+        ctx.accounts.factory.decrease_total_backing(redeem_state.from_usdc_reserve)?;
+        ctx.accounts.currency_stats.record_redeem(
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve,
+            0,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_redeem(
+            RedemptionTypeState::UsdcReserveOnly,
+            redeem_state.sovereign_amount,
+            redeem_state.from_usdc_reserve,
+            redeem_state.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.price_history.record_trade_price(
+            (redeem_state.oracle_price_mantissa, redeem_state.oracle_price_scale),
+            PriceSampleSource::Redeem,
+            &clock,
+        );
+
         emit_cpi!(SovereignCoinRedeemedEvent {
             payer: ctx.accounts.payer.key(),
             sovereign_coin: ctx.accounts.sovereign_coin.key(),
@@ -128,6 +187,18 @@ impl ExecuteRedeemFromFiat<'_> {
             timestamp: clock.unix_timestamp,
             redemption_type: RedemptionTypeState::UsdcReserveOnly,
         });
+
+        emit_cpi!(FxSpreadAppliedEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            payer: ctx.accounts.payer.key(),
+            is_mint: false,
+            oracle_rate: redeem_state.oracle_rate,
+            applied_rate: redeem_state.applied_rate,
+            spread_bps: redeem_state.spread_bps,
+            spread_amount: spread_booked,
+            spread_recipient: ctx.accounts.sovereign_coin.spread_recipient,
+            timestamp: clock.unix_timestamp,
+        });
     
         Ok(())
     }
@@ -3,14 +3,17 @@ use super::*;
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeMintSovereignCoinArgs {
     pub usdc_amount: u64,
+    pub rfq_quote: Option<RfqQuote>,   // Issuer-signed firm quote, priced instead of the oracle
 }
 
 #[derive(Accounts)]
+#[instruction(args: InitializeMintSovereignCoinArgs)]
 pub struct InitializeMintSovereignCoin<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -24,9 +27,64 @@ pub struct InitializeMintSovereignCoin<'info> {
             &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
         ],
         bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+
+    #[account(
+        mut,
+        seeds = [b"usdc_peg_guard"],
+        bump = usdc_peg_guard.bump,
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    #[account(
+        seeds = [b"dynamic_fee"],
+        bump = dynamic_fee_config.bump,
+    )]
+    pub dynamic_fee_config: Box<Account<'info, DynamicFeeConfig>>,
+
+    #[account(
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+        constraint = bond_holding.mint == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Required for basket coins, constituent (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
     // This is our state account to store calculations
     #[account(
         init,
@@ -44,61 +102,219 @@ pub struct InitializeMintSovereignCoin<'info> {
     pub payment_base_price_feed_account: UncheckedAccount<'info>,
 
     /// CHECK: Quote oracle account
+    #[account(
+        constraint = Some(payment_quote_price_feed_account.key()) == factory.payment_quote_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
     pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Set when the coin's currency is priced through a route, its leg feeds follow any basket pairs in the remaining accounts
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = price_route.bump,
+    )]
+    pub price_route: Option<Box<Account<'info, PriceRoute>>>,
+
+    /// CHECK: Instructions sysvar, holds the signature verification for an RFQ quote
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
+    // Only needed when the args carry an RFQ quote
+    #[account(
+        seeds = [b"rfq_signer", sovereign_coin.key().as_ref()],
+        bump = rfq_signer.bump,
+    )]
+    pub rfq_signer: Option<Box<Account<'info, RfqQuoteSigner>>>,
+
+    // Creating this burns the quote's nonce, a replayed quote fails here
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RfqNonce::INIT_SPACE,
+        seeds = [
+            b"rfq_nonce",
+            sovereign_coin.key().as_ref(),
+            &args.rfq_quote.as_ref().map_or(0, |quote| quote.nonce).to_le_bytes()
+        ],
+        bump
+    )]
+    pub rfq_nonce: Option<Box<Account<'info, RfqNonce>>>,
+
+    // Every trade's oracle price is checked against the TWAP here and kept for audits on execution
+    #[account(
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
     
     pub system_program: Program<'info, System>,
 }
 
-impl InitializeMintSovereignCoin<'_> {
-    pub fn handler(ctx: Context<Self>, args: InitializeMintSovereignCoinArgs) -> Result<()> {
+impl<'info> InitializeMintSovereignCoin<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>, args: InitializeMintSovereignCoinArgs) -> Result<()> {
+        // Without a quote the nonce account would be created at nonce 0 and burn it for the real quote
+        require!(
+            ctx.accounts.rfq_nonce.is_some() == args.rfq_quote.is_some(),
+            StablecoinError::InvalidRfqQuote
+        );
+
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let factory = &ctx.accounts.factory;
         let sovereign_coin = &ctx.accounts.sovereign_coin;
         let mint_state = &mut ctx.accounts.mint_state;
         
         require!(args.usdc_amount > 0, StablecoinError::InvalidAmount);
         
-        let (net_amount, protocol_fee) = fee::calculate_protocol_fee(
-            args.usdc_amount,
-            factory.transfer_fee_bps,
-        )?;
-
-        let required_reserve_percentage = reserve::calculate_required_reserve(
-            factory.min_usdc_reserve_percentage,
+        let required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            factory,
             sovereign_coin.bond_rating,
-            factory.bond_reserve_numerator,
-            factory.bond_reserve_denominator,
+            ctx.accounts.currency_stats.backing_value()?,
         )?;
 
-        let (reserve_amount, bond_amount) = reserve::calculate_reserve_and_bond_amounts(
-            net_amount,
+        // Minting tops up the reserve, so it gets cheaper the further the reserve is below target
+        let reserve_health_bps = fee::calculate_reserve_health(
+            sovereign_coin.usdc_amount,
+            sovereign_coin.bond_amount,
             required_reserve_percentage,
         )?;
+        let mint_fee_bps = ctx.accounts.dynamic_fee_config.mint_fee_bps(reserve_health_bps, factory.transfer_fee_bps)?;
 
-     let target_currency = std::str::from_utf8(&sovereign_coin.target_fiat_currency)
-            .map_err(|_| StablecoinError::InvalidFiatCurrency)?;
+        let target_currency = sovereign_coin.target_currency()?;
 
         let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
         let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
             .map(|acc| acc.to_account_info());
 
-        let sovereign_amount = conversion::calculate_sovereign_coin_amount(
-            args.usdc_amount,
-            &base_account_info,  
-            quote_account_info.as_ref(), 
+        let basket_accounts_len = match ctx.accounts.bond_basket.as_deref() {
+            Some(bond_basket) if sovereign_coin.is_basket => bond_basket.constituent_count as usize * 2,
+            _ => 0,
+        };
+        let route_feed_accounts = ctx.remaining_accounts.get(basket_accounts_len..).unwrap_or(&[]);
+        let payment_price = switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            ctx.accounts.price_route.as_deref().map(|route| (route, route_feed_accounts)),
             target_currency,
-            sovereign_coin.decimals, 
         )?;
 
         let clock = Clock::get()?;
+
+        // USD coins only convert one-to-one while USDC holds its peg, their history follows USDC/USD
+        let (usdc_depeg_price, oracle_price) = if target_currency == "USD" {
+            let usdc_price = switchboard::get_usdc_usd_price(&base_account_info)?;
+            (
+                guard_usd_conversion(&mut ctx.accounts.usdc_peg_guard, usdc_price, clock.unix_timestamp)?,
+                (usdc_price as i128, PRICE_PRECISION_DECIMALS as u32),
+            )
+        } else {
+            (None, payment_price.ok_or(StablecoinError::InvalidPriceFeed)?)
+        };
+        ctx.accounts.price_history.check_trade_price(oracle_price, clock.unix_timestamp)?;
+
+        // A signed RFQ quote stands in for the oracle rate on the trade, collateral is still marked at the oracle price
+        let rfq_price = match args.rfq_quote.as_ref() {
+            Some(rfq_quote) => {
+                let (Some(rfq_signer), Some(instructions), Some(rfq_nonce)) = (
+                    ctx.accounts.rfq_signer.as_deref(),
+                    ctx.accounts.instructions.as_ref(),
+                    ctx.accounts.rfq_nonce.as_deref_mut(),
+                ) else {
+                    return Err(StablecoinError::InvalidRfqQuote.into());
+                };
+                let price = rfq::verify_rfq_quote(
+                    rfq_quote,
+                    rfq_signer,
+                    &instructions.to_account_info(),
+                    &ctx.accounts.payer.key(),
+                    RfqSide::Mint,
+                    args.usdc_amount,
+                    oracle_price,
+                    &ctx.accounts.price_history,
+                    clock.unix_timestamp,
+                )?;
+                rfq_nonce.record_fill(rfq_quote, clock.unix_timestamp);
+
+                emit!(RfqQuoteFilledEvent {
+                    sovereign_coin: rfq_quote.sovereign_coin,
+                    taker: rfq_quote.taker,
+                    signer: rfq_signer.signer,
+                    side: rfq_quote.side,
+                    amount: rfq_quote.amount,
+                    rate: rfq_quote.rate,
+                    nonce: rfq_quote.nonce,
+                    timestamp: clock.unix_timestamp,
+                });
+                Some(price)
+            }
+            None => None,
+        };
+        let (trade_price, trade_depeg_price) = match rfq_price {
+            Some(price) => (Some(price), None),
+            None => (payment_price, usdc_depeg_price),
+        };
+
+        let quote = quote_mint(
+            sovereign_coin,
+            args.usdc_amount,
+            mint_fee_bps,
+            required_reserve_percentage,
+            trade_price,
+            trade_depeg_price,
+        )?;
+
+        // Stop new supply while the bonds, marked to market, no longer cover what is outstanding
+        let collateral_mark = valuation::mark_collateral(
+            sovereign_coin,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.bond_token_mint,
+            &ctx.accounts.bond_holding,
+            ctx.accounts.bond_basket.as_deref(),
+            ctx.remaining_accounts,
+            payment_price,
+            clock.unix_timestamp,
+        )?;
+        let min_collateral_ratio_bps = ctx.accounts.reserve_policy.min_mint_collateral_ratio_bps;
+        require!(
+            min_collateral_ratio_bps == 0 || collateral_mark.collateral_ratio_bps >= min_collateral_ratio_bps as u64,
+            StablecoinError::CollateralRatioTooLow
+        );
+
+        // The USDC buys an accrued UI amount, interest-bearing coins mint fewer raw tokens for it
+        let sovereign_amount = if sovereign_coin.is_interest_bearing {
+            token_extension::accrued_amount_to_raw_amount(
+                &ctx.accounts.mint.to_account_info(),
+                quote.accrued_sovereign_amount,
+                clock.unix_timestamp,
+            )?
+        } else {
+            quote.accrued_sovereign_amount
+        };
+
         mint_state.payer = ctx.accounts.payer.key();
         mint_state.sovereign_coin = ctx.accounts.sovereign_coin.key();
         mint_state.usdc_amount = args.usdc_amount;
         mint_state.sovereign_amount = sovereign_amount;
-        mint_state.reserve_amount = reserve_amount;
-        mint_state.bond_amount = bond_amount;
-        mint_state.protocol_fee = protocol_fee;
+        mint_state.reserve_amount = quote.reserve_amount;
+        mint_state.bond_amount = quote.bond_amount;
+        mint_state.protocol_fee = quote.protocol_fee;
+        mint_state.spread_amount = quote.spread_amount;
+        mint_state.spread_bps = sovereign_coin.mint_spread_bps;
+        mint_state.oracle_rate = quote.oracle_rate;
+        mint_state.applied_rate = quote.applied_rate;
+        (mint_state.oracle_price_mantissa, mint_state.oracle_price_scale) = oracle_price;
         mint_state.created_at = clock.unix_timestamp;
         mint_state.bump = ctx.bumps.mint_state;
+
+        ctx.accounts.sovereign_coin.record_collateral_mark(&collateral_mark, clock.unix_timestamp);
         
         Ok(())
     }
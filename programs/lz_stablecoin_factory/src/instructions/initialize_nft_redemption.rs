@@ -19,6 +19,8 @@ pub struct InitializeNFTRedemption<'info> {
             &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
         ],
         bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
+        constraint = !sovereign_coin.is_basket @ StablecoinError::BasketNotSupported,
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
 
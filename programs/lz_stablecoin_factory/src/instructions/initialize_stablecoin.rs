@@ -6,6 +6,8 @@ pub struct SovereignCoinArgs {
     pub symbol: String,
     pub uri: String,
     pub fiat_currency: String,
+    pub yield_mode: YieldMode,
+    pub reward_payout: RewardPayout,  // Only used by claimable coins
 }
 
 #[event_cpi]
@@ -29,6 +31,40 @@ pub struct InitSovereignCoin<'info> {
         bump
     )]
     pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    // Aggregate stats shared by every coin targeting the same fiat currency
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [b"currency_stats", args.fiat_currency.as_bytes()],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SovereignCoinStats::INIT_SPACE,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + PriceHistory::INIT_SPACE,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
     
    
     pub fiat_token_mint: Box<InterfaceAccount<'info, Mint>>,
@@ -144,14 +180,13 @@ impl InitSovereignCoin<'_> {
         sovereign_coin.bond_rating = selected_mapping.bond_rating;
         
         
-        sovereign_coin.required_reserve_percentage = calculate_required_reserve(
-            ctx.accounts.factory.min_usdc_reserve_percentage,
+        // Same policy the mint path applies, so a new coin starts on the requirement it will be held to
+        sovereign_coin.required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            &ctx.accounts.factory,
             selected_mapping.bond_rating,
-            ctx.accounts.factory.bond_reserve_numerator,
-            ctx.accounts.factory.bond_reserve_denominator
-        )?
-        .try_into()
-        .map_err(|_| StablecoinError::ReservePercentageOverflow)?;
+            ctx.accounts.currency_stats.backing_value()?,
+        )?;
         
         
         sovereign_coin.decimals = 6;
@@ -160,6 +195,38 @@ impl InitSovereignCoin<'_> {
         sovereign_coin.bond_amount = 0;
         sovereign_coin.interest_rate = 0;
         sovereign_coin.is_interest_bearing = false;
+        sovereign_coin.lifecycle = SovereignCoinLifecycle::Active;
+        sovereign_coin.settled_at = 0;
+        sovereign_coin.currency_stats_tracked = true;
+        sovereign_coin.is_basket = false;
+        sovereign_coin.net_yield_rate = 0;
+        sovereign_coin.protocol_yield_accrued = 0;
+        sovereign_coin.issuer_yield_accrued = 0;
+        sovereign_coin.yield_accrued_at = 0;
+        sovereign_coin.yield_mode = args.yield_mode;
+        sovereign_coin.reward_payout = args.reward_payout;
+        sovereign_coin.reward_index = 0;
+        sovereign_coin.reward_deposits = 0;
+        sovereign_coin.holder_yield_accrued = 0;
+        sovereign_coin.reward_usdc_balance = 0;
+        sovereign_coin.marked_bond_value = 0;
+        sovereign_coin.collateral_ratio_bps = 0;
+        sovereign_coin.collateral_marked_at = 0;
+        sovereign_coin.mint_spread_bps = 0;
+        sovereign_coin.redeem_spread_bps = 0;
+        sovereign_coin.spread_recipient = SpreadRecipient::Protocol;
+        sovereign_coin.spread_revenue_accrued = 0;
+
+        let currency_stats = &mut ctx.accounts.currency_stats;
+        currency_stats.bump = ctx.bumps.currency_stats;
+        currency_stats.fiat_currency = sovereign_coin.target_fiat_currency;
+        currency_stats.sovereign_coin_count = currency_stats.sovereign_coin_count.safe_add(1)?;
+
+        let sovereign_coin_stats = &mut ctx.accounts.sovereign_coin_stats;
+        sovereign_coin_stats.bump = ctx.bumps.sovereign_coin_stats;
+        sovereign_coin_stats.sovereign_coin = sovereign_coin.key();
+
+        ctx.accounts.price_history.initialize(ctx.bumps.price_history, sovereign_coin.key());
 
         
         let clock = Clock::get()?;
@@ -114,8 +114,16 @@ impl InitLzOft<'_> {
             StablecoinError::LzInvalidDecimals
         );
 
+        // Local decimals are the coin's own, which need not match USDC's
+        require!(
+            ctx.accounts.token_mint.decimals == sovereign_coin.decimals,
+            StablecoinError::LzInvalidDecimals
+        );
+
         oft_store.oft_type = params.oft_type.clone();
-        oft_store.ld2sd_rate = 10u64.pow((ctx.accounts.token_mint.decimals - params.shared_decimals) as u32);
+        oft_store.ld2sd_rate = 10u64
+            .checked_pow((ctx.accounts.token_mint.decimals - params.shared_decimals) as u32)
+            .ok_or(StablecoinError::LzInvalidDecimals)?;
         oft_store.token_mint = ctx.accounts.token_mint.key();
         oft_store.token_escrow = ctx.accounts.token_escrow.key();
         oft_store.endpoint_program = params.endpoint_program.unwrap_or(ENDPOINT_ID);
@@ -77,7 +77,7 @@ impl QuoteLzSend<'_> {
         );
 
         // Convert to shared decimals for cross-chain transfer
-        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld);
+        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld)?;
 
         // Call LayerZero endpoint to get quote
         oapp::endpoint_cpi::quote(
@@ -216,7 +216,7 @@ impl QuoteStablecoinCrossChain<'_> {
         );
 
         // Get LayerZero messaging fee
-        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld);
+        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld)?;
         let lz_messaging_fee = oapp::endpoint_cpi::quote(
             ctx.accounts.oft_store.endpoint_program,
             ctx.remaining_accounts,
@@ -8,6 +8,7 @@ pub struct LzReceive<'info> {
     pub payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -25,6 +26,25 @@ pub struct LzReceive<'info> {
     )]
     pub sovereign_coin: Account<'info, SovereignCoin>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     #[account(
         mut,
         seeds = [
@@ -92,6 +112,12 @@ pub struct LzReceive<'info> {
 impl LzReceive<'_> {
     pub fn apply(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Result<()> {
         require!(!ctx.accounts.oft_store.paused, StablecoinError::LzPaused);
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
 
         let oft_store_seed = ctx.accounts.token_escrow.key();
         let seeds: &[&[u8]] = &[OFT_SEED, oft_store_seed.as_ref(), &[ctx.accounts.oft_store.bump]];
@@ -115,7 +141,7 @@ impl LzReceive<'_> {
 
         // Convert the amount from shared decimals to local decimals
         let amount_sd = msg_codec::amount_sd(&params.message);
-        let mut amount_received_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
+        let mut amount_received_ld = ctx.accounts.oft_store.sd2ld(amount_sd)?;
 
         // Apply rate limiting
         if let Some(rate_limiter) = ctx.accounts.peer.inbound_rate_limiter.as_mut() {
@@ -142,6 +168,23 @@ impl LzReceive<'_> {
         ctx.accounts.sovereign_coin.total_supply = ctx.accounts.sovereign_coin.total_supply
             .checked_add(amount_received_ld)
             .ok_or(StablecoinError::MathOverflow)?;
+        ctx.accounts.factory.increase_total_supply(amount_received_ld)?;
+        ctx.accounts.currency_stats.record_bridge_in(amount_received_ld, Clock::get()?.unix_timestamp)?;
+        let peer_tracked = ctx.accounts.sovereign_coin_stats.record_bridge_in(
+            params.src_eid,
+            amount_received_ld,
+            Clock::get()?.unix_timestamp,
+        )?;
+        if !peer_tracked {
+            emit_cpi!(PeerStatsUntrackedEvent {
+                sovereign_coin: ctx.accounts.sovereign_coin.key(),
+                eid: params.src_eid,
+                is_inbound: true,
+                amount: amount_received_ld,
+                max_tracked_peers: MAX_TRACKED_PEERS as u8,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
 
         // Handle compose message if present
         if let Some(message) = msg_codec::compose_msg(&params.message) {
@@ -279,6 +322,15 @@ impl LzReceiveTypes<'_> {
             &[PEER_SEED, ctx.accounts.oft_store.key().as_ref(), &params.src_eid.to_be_bytes()],
             ctx.program_id,
         );
+        let fiat_currency = &ctx.accounts.sovereign_coin.target_fiat_currency;
+        let (currency_stats, _) = Pubkey::find_program_address(
+            &[b"currency_stats", &fiat_currency[..fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]],
+            ctx.program_id,
+        );
+        let (sovereign_coin_stats, _) = Pubkey::find_program_address(
+            &[b"sovereign_coin_stats", ctx.accounts.sovereign_coin.key().as_ref()],
+            ctx.program_id,
+        );
 
         // Build account list for LzReceive instruction
         let mut accounts = vec![
@@ -290,28 +342,38 @@ impl LzReceiveTypes<'_> {
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: ctx.accounts.factory.key(), 
                 is_signer: false, 
-                is_writable: false 
+                is_writable: true 
             }, // 1: factory
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: ctx.accounts.sovereign_coin.key(), 
                 is_signer: false, 
                 is_writable: true 
             }, // 2: sovereign_coin
+            oapp::endpoint_cpi::LzAccount { 
+                pubkey: currency_stats, 
+                is_signer: false, 
+                is_writable: true 
+            }, // 3: currency_stats
+            oapp::endpoint_cpi::LzAccount { 
+                pubkey: sovereign_coin_stats, 
+                is_signer: false, 
+                is_writable: true 
+            }, // 4: sovereign_coin_stats
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: peer, 
                 is_signer: false, 
                 is_writable: true 
-            }, // 3: peer
+            }, // 5: peer
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: ctx.accounts.oft_store.key(), 
                 is_signer: false, 
                 is_writable: true 
-            }, // 4: oft_store
+            }, // 6: oft_store
             oapp::endpoint_cpi::LzAccount {
                 pubkey: ctx.accounts.oft_store.token_escrow.key(),
                 is_signer: false,
                 is_writable: true,
-            }, // 5: token_escrow
+            }, // 7: token_escrow
         ];
 
         // Add recipient and token accounts
@@ -333,32 +395,32 @@ impl LzReceiveTypes<'_> {
                 pubkey: to_address, 
                 is_signer: false, 
                 is_writable: false 
-            }, // 6: to_address
+            }, // 8: to_address
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: token_dest, 
                 is_signer: false, 
                 is_writable: true 
-            }, // 7: token_dest
+            }, // 9: token_dest
             oapp::endpoint_cpi::LzAccount {
                 pubkey: ctx.accounts.token_mint.key(),
                 is_signer: false,
                 is_writable: true,
-            }, // 8: token_mint
+            }, // 10: token_mint
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: mint_authority, 
                 is_signer: false, 
                 is_writable: false 
-            }, // 9: mint_authority
+            }, // 11: mint_authority
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: *token_program, 
                 is_signer: false, 
                 is_writable: false 
-            }, // 10: token_program
+            }, // 12: token_program
             oapp::endpoint_cpi::LzAccount { 
                 pubkey: ASSOCIATED_TOKEN_ID, 
                 is_signer: false, 
                 is_writable: false 
-            }, // 11: associated_token_program
+            }, // 13: associated_token_program
         ]);
 
         // Add system program and event authority
@@ -369,7 +431,7 @@ impl LzReceiveTypes<'_> {
                 pubkey: solana_program::system_program::ID,
                 is_signer: false,
                 is_writable: false,
-            }, // 12: system_program
+            }, // 14: system_program
         ]);
 
         let endpoint_program = ctx.accounts.oft_store.endpoint_program;
@@ -387,7 +449,7 @@ impl LzReceiveTypes<'_> {
         // Add accounts for compose message if present
         if let Some(message) = msg_codec::compose_msg(&params.message) {
             let amount_sd = msg_codec::amount_sd(&params.message);
-            let amount_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
+            let amount_ld = ctx.accounts.oft_store.sd2ld(amount_sd)?;
             let amount_received_ld = if ctx.accounts.oft_store.oft_type == OFTType::Native {
                 amount_ld
             } else {
@@ -17,9 +17,11 @@ pub struct LzSendParams {
 #[derive(Accounts)]
 #[instruction(params: LzSendParams)]
 pub struct LzSend<'info> {
+    #[account(mut)]
     pub signer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"factory"],
         bump = factory.bump,
     )]
@@ -34,9 +36,29 @@ pub struct LzSend<'info> {
         ],
         bump = sovereign_coin.bump,
         constraint = sovereign_coin.is_cross_chain_enabled @ StablecoinError::LzNotEnabled,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
     )]
     pub sovereign_coin: Account<'info, SovereignCoin>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
     #[account(
         mut,
         seeds = [
@@ -90,6 +112,7 @@ pub struct LzSend<'info> {
     pub protocol_vault: InterfaceAccount<'info, TokenAccount>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 impl LzSend<'_> {
@@ -98,6 +121,12 @@ impl LzSend<'_> {
         params: &LzSendParams,
     ) -> Result<(MessagingReceipt, StablecoinCrossChainReceipt)> {
         require!(!ctx.accounts.oft_store.paused, StablecoinError::LzPaused);
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
 
         // Calculate comprehensive fee breakdown
         let (amount_sent_ld, amount_received_ld, protocol_fee_ld, transfer_fee_ld, lz_fee_ld) = 
@@ -133,6 +162,24 @@ impl LzSend<'_> {
         ctx.accounts.sovereign_coin.total_supply = ctx.accounts.sovereign_coin.total_supply
             .checked_sub(amount_received_ld)
             .ok_or(StablecoinError::MathOverflow)?;
+        ctx.accounts.factory.decrease_total_supply(amount_received_ld)?;
+        ctx.accounts.currency_stats.record_bridge_out(amount_received_ld, Clock::get()?.unix_timestamp)?;
+        let peer_tracked = ctx.accounts.sovereign_coin_stats.record_bridge_out(
+            params.dst_eid,
+            amount_received_ld,
+            protocol_fee_ld,
+            Clock::get()?.unix_timestamp,
+        )?;
+        if !peer_tracked {
+            emit_cpi!(PeerStatsUntrackedEvent {
+                sovereign_coin: ctx.accounts.sovereign_coin.key(),
+                eid: params.dst_eid,
+                is_inbound: false,
+                amount: amount_received_ld,
+                max_tracked_peers: MAX_TRACKED_PEERS as u8,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
 
         // Send cross-chain message
         require!(
@@ -140,7 +187,7 @@ impl LzSend<'_> {
             StablecoinError::LzInvalidSender
         );
 
-        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld);
+        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld)?;
         let msg_receipt = oapp::endpoint_cpi::send(
             ctx.accounts.oft_store.endpoint_program,
             ctx.accounts.oft_store.key(),
@@ -0,0 +1,393 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintWithBondsArgs {
+    pub bond_amount: u64,             // Raw bond tokens deposited
+    pub usdc_amount: u64,             // USDC top-up, must cover the fee, the spread and the reserve share
+    pub min_sovereign_amount: u64,    // Raw coins the depositor accepts at least
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: MintWithBondsArgs)]
+pub struct MintWithBonds<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
+        constraint = !sovereign_coin.is_basket @ StablecoinError::BasketNotSupported,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+
+    #[account(
+        mut,
+        seeds = [b"usdc_peg_guard"],
+        bump = usdc_peg_guard.bump,
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    #[account(
+        seeds = [b"dynamic_fee"],
+        bump = dynamic_fee_config.bump,
+    )]
+    pub dynamic_fee_config: Box<Account<'info, DynamicFeeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", sovereign_coin.key().as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+    )]
+    pub user_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Depositor's Stablebond account (to pay from)
+    #[account(
+        mut,
+        token::mint = bond_token_mint,
+        token::authority = payer,
+        constraint = user_bond_account.amount >= args.bond_amount @ StablecoinError::InsufficientBondBalance,
+    )]
+    pub user_bond_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+        constraint = bond_holding.mint == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Depositor's USDC account for the top-up
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = payer,
+    )]
+    pub user_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+
+    /// CHECK: Quote oracle account
+    pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Set when the coin's currency is priced through a route, its leg feeds are the remaining accounts
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = price_route.bump,
+    )]
+    pub price_route: Option<Box<Account<'info, PriceRoute>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintWithBonds<'info> {
+    /// Mint against Stablebonds the depositor already holds. The bonds are valued at the oracle price
+    /// and stand in for the bond share of a USDC mint, the top-up pays the fee, the spread and the reserve.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>, args: MintWithBondsArgs) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        require!(args.bond_amount > 0, StablecoinError::InvalidAmount);
+
+        let clock = Clock::get()?;
+        let factory = &ctx.accounts.factory;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let target_currency = sovereign_coin.target_currency()?;
+
+        let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
+        let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
+            .map(|acc| acc.to_account_info());
+        let payment_price = switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            ctx.accounts.price_route.as_deref().map(|route| (route, ctx.remaining_accounts)),
+            target_currency,
+        )?;
+
+        // USD coins only convert one-to-one while USDC holds its peg, their history follows USDC/USD
+        let (usdc_depeg_price, oracle_price) = if target_currency == "USD" {
+            let usdc_price = switchboard::get_usdc_usd_price(&base_account_info)?;
+            (
+                guard_usd_conversion(&mut ctx.accounts.usdc_peg_guard, usdc_price, clock.unix_timestamp)?,
+                (usdc_price as i128, PRICE_PRECISION_DECIMALS as u32),
+            )
+        } else {
+            (None, payment_price.ok_or(StablecoinError::InvalidPriceFeed)?)
+        };
+        ctx.accounts.price_history.check_trade_price(oracle_price, clock.unix_timestamp)?;
+        ctx.accounts.price_history.record_trade_price(oracle_price, PriceSampleSource::Mint, &clock);
+
+        // Stop new supply while the bonds, marked to market, no longer cover what is outstanding
+        let collateral_mark = valuation::mark_collateral(
+            sovereign_coin,
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.bond_token_mint,
+            &ctx.accounts.bond_holding,
+            None,
+            ctx.remaining_accounts,
+            payment_price,
+            clock.unix_timestamp,
+        )?;
+        let min_collateral_ratio_bps = ctx.accounts.reserve_policy.min_mint_collateral_ratio_bps;
+        require!(
+            min_collateral_ratio_bps == 0 || collateral_mark.collateral_ratio_bps >= min_collateral_ratio_bps as u64,
+            StablecoinError::CollateralRatioTooLow
+        );
+
+        // Value what actually lands in the holding, a bond transfer fee comes out of the depositor's side
+        let previous_bond_holding = ctx.accounts.bond_holding.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_bond_account.to_account_info(),
+                    mint: ctx.accounts.bond_token_mint.to_account_info(),
+                    to: ctx.accounts.bond_holding.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            args.bond_amount,
+            ctx.accounts.bond_token_mint.decimals,
+        )?;
+        ctx.accounts.bond_holding.reload()?;
+        let bonds_received = ctx.accounts.bond_holding.amount.safe_sub(previous_bond_holding)?;
+
+        let bond_value = valuation::mark_bond_to_market(
+            &ctx.accounts.bond_token_mint.to_account_info(),
+            ctx.accounts.bond_token_mint.decimals,
+            bonds_received,
+            payment_price,
+            clock.unix_timestamp,
+        )?;
+        require!(bond_value > 0, StablecoinError::InvalidAmount);
+
+        let required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            factory,
+            sovereign_coin.bond_rating,
+            ctx.accounts.currency_stats.backing_value()?,
+        )?;
+        let reserve_health_bps = fee::calculate_reserve_health(
+            sovereign_coin.usdc_amount,
+            sovereign_coin.bond_amount,
+            required_reserve_percentage,
+        )?;
+        let mint_fee_bps = ctx.accounts.dynamic_fee_config.mint_fee_bps(reserve_health_bps, factory.transfer_fee_bps)?;
+
+        // Priced as a USDC mint of the bonds' value plus the top-up
+        let deposit_value = bond_value.safe_add(args.usdc_amount)?;
+        let quote = quote_mint(
+            sovereign_coin,
+            deposit_value,
+            mint_fee_bps,
+            required_reserve_percentage,
+            payment_price,
+            usdc_depeg_price,
+        )?;
+
+        // The bonds can only fill the bond share, the USDC has to cover everything else or the mint fails
+        let usdc_required = quote.protocol_fee
+            .safe_add(quote.spread_amount)?
+            .safe_add(quote.reserve_amount)?;
+        require!(args.usdc_amount >= usdc_required, StablecoinError::ReserveTopUpTooLow);
+        // Top-up beyond the required share also stays in the reserve
+        let reserve_amount = args.usdc_amount
+            .safe_sub(quote.protocol_fee)?
+            .safe_sub(quote.spread_amount)?;
+
+        let sovereign_amount = if sovereign_coin.is_interest_bearing {
+            token_extension::accrued_amount_to_raw_amount(
+                &ctx.accounts.mint.to_account_info(),
+                quote.accrued_sovereign_amount,
+                clock.unix_timestamp,
+            )?
+        } else {
+            quote.accrued_sovereign_amount
+        };
+        require!(sovereign_amount > 0, StablecoinError::InvalidAmount);
+        require!(sovereign_amount >= args.min_sovereign_amount, StablecoinError::MintAmountBelowMinimum);
+
+        if quote.protocol_fee > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.user_usdc_token_account.to_account_info(),
+                        mint: ctx.accounts.usdc_mint.to_account_info(),
+                        to: ctx.accounts.usdc_protocol_vault.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                quote.protocol_fee,
+                ctx.accounts.usdc_mint.decimals,
+            )?;
+        }
+
+        // The spread is held in the reserve for its recipient, as with a USDC mint
+        let reserve_transfer = reserve_amount.safe_add(quote.spread_amount)?;
+        if reserve_transfer > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.user_usdc_token_account.to_account_info(),
+                        mint: ctx.accounts.usdc_mint.to_account_info(),
+                        to: ctx.accounts.global_usdc_reserve.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                reserve_transfer,
+                ctx.accounts.usdc_mint.decimals,
+            )?;
+        }
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.user_sovereign_coin_account.to_account_info(),
+                    authority: ctx.accounts.factory.to_account_info(),
+                },
+                factory_signer,
+            ),
+            sovereign_amount,
+        )?;
+
+        // Yield claims accrue on the bond balance before this mint changes it
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.safe_add(sovereign_amount)?;
+        sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_add(reserve_amount)?;
+        sovereign_coin.bond_amount = sovereign_coin.bond_amount.safe_add(bond_value)?;
+        sovereign_coin.spread_revenue_accrued = sovereign_coin.spread_revenue_accrued.safe_add(quote.spread_amount)?;
+        sovereign_coin.record_collateral_mark(&collateral_mark, clock.unix_timestamp);
+
+        ctx.accounts.factory.increase_total_supply(sovereign_amount)?;
+        ctx.accounts.factory.increase_total_backing(reserve_amount.safe_add(bond_value)?)?;
+        ctx.accounts.currency_stats.record_mint(
+            sovereign_amount,
+            reserve_amount,
+            bond_value,
+            quote.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_mint(
+            sovereign_amount,
+            deposit_value,
+            quote.protocol_fee,
+            clock.unix_timestamp,
+        )?;
+
+        emit_cpi!(SovereignCoinMintedWithBondsEvent {
+            payer: ctx.accounts.payer.key(),
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            bond_amount: bonds_received,
+            bond_value,
+            usdc_amount: args.usdc_amount,
+            sovereign_coin_amount: sovereign_amount,
+            reserve_amount,
+            protocol_fee: quote.protocol_fee,
+            oracle_rate: quote.oracle_rate,
+            applied_rate: quote.applied_rate,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -6,18 +6,41 @@ pub mod initialize_stablecoin;
 pub mod execute_create_stablecoin;
 pub mod initialize_mint_stablecoin;
 pub mod execute_mint_stablecoin;
+pub mod mint_with_bonds;
 pub mod initialize_redeem_stablecoin;
 pub mod execute_redeem_fiat;
 pub mod execute_redeem_fiat_protocol;
 pub mod execute_instant_redeem;
+pub mod redeem_to_bonds;
+pub mod configure_in_kind_redemption;
 pub mod initialize_nft_redemption;
 pub mod complete_nft_redemption;
 // pub mod execute_nft_redeem;
 pub mod update_price_feed;
-// pub mod preview_exchange;
+pub mod preview_mint;
+pub mod preview_redeem;
 pub mod setup_bond_holding;
 pub mod setup_bond_info;
 pub mod setup_ibt_and_transfer_fee;
+pub mod setup_ibt_with_token_metadata;
+pub mod update_sovereign_coin_metadata;
+pub mod enter_redeem_only;
+pub mod settle_sovereign_coin;
+pub mod claim_settlement;
+pub mod close_sovereign_coin;
+pub mod crank_interest_rate;
+pub mod setup_bond_basket;
+pub mod settle_yield_claims;
+pub mod deposit_for_rewards;
+pub mod withdraw_reward_deposit;
+pub mod claim_rewards;
+pub mod refresh_reserve_requirement;
+pub mod configure_fx_spread;
+pub mod withdraw_spread_revenue;
+pub mod check_usdc_peg;
+pub mod configure_rfq_signer;
+pub mod crank_price_history;
+pub mod read_price_history;
 pub mod lz_ixs;
 
 
@@ -26,18 +49,41 @@ pub use initialize_stablecoin::*;
 pub use execute_create_stablecoin::*;
 pub use initialize_mint_stablecoin::*;
 pub use execute_mint_stablecoin::*;
+pub use mint_with_bonds::*;
 pub use initialize_redeem_stablecoin::*;
 pub use execute_redeem_fiat::*;
 pub use execute_redeem_fiat_protocol::*;
 pub use execute_instant_redeem::*;
+pub use redeem_to_bonds::*;
+pub use configure_in_kind_redemption::*;
 pub use initialize_nft_redemption::*;
 pub use complete_nft_redemption::*;
 // pub use execute_nft_redeem::*;
 pub use update_price_feed::*;
-// pub use preview_exchange::*;
+pub use preview_mint::*;
+pub use preview_redeem::*;
 pub use setup_bond_holding::*;
 pub use setup_bond_info::*;
 pub use setup_ibt_and_transfer_fee::*;
+pub use setup_ibt_with_token_metadata::*;
+pub use update_sovereign_coin_metadata::*;
+pub use enter_redeem_only::*;
+pub use settle_sovereign_coin::*;
+pub use claim_settlement::*;
+pub use close_sovereign_coin::*;
+pub use crank_interest_rate::*;
+pub use setup_bond_basket::*;
+pub use settle_yield_claims::*;
+pub use deposit_for_rewards::*;
+pub use withdraw_reward_deposit::*;
+pub use claim_rewards::*;
+pub use refresh_reserve_requirement::*;
+pub use configure_fx_spread::*;
+pub use withdraw_spread_revenue::*;
+pub use check_usdc_peg::*;
+pub use configure_rfq_signer::*;
+pub use crank_price_history::*;
+pub use read_price_history::*;
 pub use lz_ixs::*;
 
 
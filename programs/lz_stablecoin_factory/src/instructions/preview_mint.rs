@@ -0,0 +1,172 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PreviewMintArgs {
+    pub usdc_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PreviewMintResult {
+    pub usdc_amount: u64,
+    pub mint_fee_bps: u16,
+    pub protocol_fee: u64,
+    pub spread_amount: u64,
+    pub required_reserve_percentage: u16,
+    pub reserve_amount: u64,
+    pub bond_amount: u64,
+    pub sovereign_amount: u64,    // Raw tokens minted
+    pub transfer_fee: u64,        // Token-2022 fee a holder pays to move the minted coins
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+    pub usdc_depegged: bool,      // A USD coin priced at the USDC/USD rate instead of one-to-one
+}
+
+#[derive(Accounts)]
+pub struct PreviewMint<'info> {
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = currency_stats.bump,
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+
+    // Read only, the preview applies the latest USDC/USD reading to a copy
+    #[account(
+        seeds = [b"usdc_peg_guard"],
+        bump = usdc_peg_guard.bump,
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    #[account(
+        seeds = [b"dynamic_fee"],
+        bump = dynamic_fee_config.bump,
+    )]
+    pub dynamic_fee_config: Box<Account<'info, DynamicFeeConfig>>,
+
+    #[account(
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+
+    /// CHECK: Quote oracle account
+    pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Set when the coin's currency is priced through a route, its leg feeds are the remaining accounts
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = price_route.bump,
+    )]
+    pub price_route: Option<Box<Account<'info, PriceRoute>>>,
+}
+
+impl PreviewMint<'_> {
+    /// Quote a mint without touching any state. The collateral ratio gate is not evaluated,
+    /// so a mint that previews cleanly can still be refused by initialize_mint.
+    pub fn handler(ctx: Context<Self>, args: PreviewMintArgs) -> Result<PreviewMintResult> {
+        let factory = &ctx.accounts.factory;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+
+        require!(args.usdc_amount > 0, StablecoinError::InvalidAmount);
+
+        let required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            factory,
+            sovereign_coin.bond_rating,
+            ctx.accounts.currency_stats.backing_value()?,
+        )?;
+        let reserve_health_bps = fee::calculate_reserve_health(
+            sovereign_coin.usdc_amount,
+            sovereign_coin.bond_amount,
+            required_reserve_percentage,
+        )?;
+        let mint_fee_bps = ctx.accounts.dynamic_fee_config.mint_fee_bps(reserve_health_bps, factory.transfer_fee_bps)?;
+
+        let target_currency = sovereign_coin.target_currency()?;
+
+        let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
+        let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
+            .map(|acc| acc.to_account_info());
+
+        let payment_price = switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            ctx.accounts.price_route.as_deref().map(|route| (route, ctx.remaining_accounts)),
+            target_currency,
+        )?;
+        let usdc_depeg_price = if target_currency == "USD" {
+            let usdc_price = switchboard::get_usdc_usd_price(&base_account_info)?;
+            ctx.accounts.usdc_peg_guard.preview_conversion_price(usdc_price)?
+        } else {
+            None
+        };
+
+        let quote = quote_mint(
+            sovereign_coin,
+            args.usdc_amount,
+            mint_fee_bps,
+            required_reserve_percentage,
+            payment_price,
+            usdc_depeg_price,
+        )?;
+
+        let mint_account_info = ctx.accounts.mint.to_account_info();
+        let sovereign_amount = if sovereign_coin.is_interest_bearing {
+            token_extension::accrued_amount_to_raw_amount(
+                &mint_account_info,
+                quote.accrued_sovereign_amount,
+                Clock::get()?.unix_timestamp,
+            )?
+        } else {
+            quote.accrued_sovereign_amount
+        };
+
+        Ok(PreviewMintResult {
+            usdc_amount: args.usdc_amount,
+            mint_fee_bps,
+            protocol_fee: quote.protocol_fee,
+            spread_amount: quote.spread_amount,
+            required_reserve_percentage,
+            reserve_amount: quote.reserve_amount,
+            bond_amount: quote.bond_amount,
+            sovereign_amount,
+            transfer_fee: token_extension::expected_transfer_fee(&mint_account_info, sovereign_amount)?,
+            oracle_rate: quote.oracle_rate,
+            applied_rate: quote.applied_rate,
+            usdc_depegged: usdc_depeg_price.is_some(),
+        })
+    }
+}
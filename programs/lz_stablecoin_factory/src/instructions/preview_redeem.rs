@@ -0,0 +1,218 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PreviewRedeemArgs {
+    pub sovereign_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PreviewRedeemResult {
+    pub sovereign_amount: u64,
+    pub usdc_amount: u64,         // Value of the coins once the spread is withheld
+    pub spread_amount: u64,
+    pub collateral_haircut: u64,
+    pub redeem_fee_bps: u16,
+    pub protocol_fee: u64,
+    pub net_amount: u64,
+    pub from_usdc_reserve: u64,
+    pub from_protocol_vault: u64,
+    pub from_bond_redemption: u64,
+    pub redemption_type: RedemptionTypeState,
+    pub transfer_fee: u64,        // Token-2022 fee a holder pays to move the coins being redeemed
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+    pub usdc_depegged: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args: PreviewRedeemArgs)]
+pub struct PreviewRedeem<'info> {
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    // The holder's coins set their share of the reserve, without it the redeemed amount is taken as the whole balance
+    #[account(
+        token::mint = mint,
+        constraint = holder_sovereign_coin_account.amount >= args.sovereign_amount @ StablecoinError::InsufficientBalance
+    )]
+    pub holder_sovereign_coin_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Read only, the preview applies the latest USDC/USD reading to a copy
+    #[account(
+        seeds = [b"usdc_peg_guard"],
+        bump = usdc_peg_guard.bump,
+    )]
+    pub usdc_peg_guard: Box<Account<'info, UsdcPegGuard>>,
+
+    #[account(
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = currency_stats.bump,
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+
+    #[account(
+        seeds = [b"dynamic_fee"],
+        bump = dynamic_fee_config.bump,
+    )]
+    pub dynamic_fee_config: Box<Account<'info, DynamicFeeConfig>>,
+
+    #[account(
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+
+    /// CHECK: Quote oracle account
+    pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Set when the coin's currency is priced through a route, its leg feeds are the remaining accounts
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = price_route.bump,
+    )]
+    pub price_route: Option<Box<Account<'info, PriceRoute>>>,
+}
+
+impl PreviewRedeem<'_> {
+    /// Quote a redemption, including its waterfall, without touching any state.
+    /// The collateral haircut uses the coin's last collateral mark.
+    pub fn handler(ctx: Context<Self>, args: PreviewRedeemArgs) -> Result<PreviewRedeemResult> {
+        let factory = &ctx.accounts.factory;
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let sovereign_amount = args.sovereign_amount;
+
+        require!(sovereign_amount > 0, StablecoinError::InvalidAmount);
+
+        let clock = Clock::get()?;
+        let mint_account_info = ctx.accounts.mint.to_account_info();
+        let to_accrued = |raw_amount: u64| -> Result<u64> {
+            if sovereign_coin.is_interest_bearing {
+                token_extension::raw_amount_to_accrued_amount(&mint_account_info, raw_amount, clock.unix_timestamp)
+            } else {
+                Ok(raw_amount)
+            }
+        };
+
+        let target_currency = sovereign_coin.target_currency()?;
+
+        let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
+        let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
+            .map(|acc| acc.to_account_info());
+
+        let payment_price = switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            ctx.accounts.price_route.as_deref().map(|route| (route, ctx.remaining_accounts)),
+            target_currency,
+        )?;
+        let usdc_depeg_price = if target_currency == "USD" {
+            let usdc_price = switchboard::get_usdc_usd_price(&base_account_info)?;
+            ctx.accounts.usdc_peg_guard.preview_conversion_price(usdc_price)?
+        } else {
+            None
+        };
+
+        // Measured against the live policy target, the same one the mint fee uses
+        let required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            factory,
+            sovereign_coin.bond_rating,
+            ctx.accounts.currency_stats.backing_value()?,
+        )?;
+        let reserve_health_bps = fee::calculate_reserve_health(
+            sovereign_coin.usdc_amount,
+            sovereign_coin.bond_amount,
+            required_reserve_percentage,
+        )?;
+        let redeem_fee_bps = ctx.accounts.dynamic_fee_config.redeem_fee_bps(reserve_health_bps, factory.transfer_fee_bps)?;
+
+        let holder_balance = ctx.accounts.holder_sovereign_coin_account.as_ref()
+            .map_or(sovereign_amount, |account| account.amount);
+        let user_share_of_fiat_reserve = utils::mul_div(
+            to_accrued(holder_balance)?,
+            sovereign_coin.usdc_amount,
+            to_accrued(sovereign_coin.total_supply)?,
+            Rounding::Down,
+        )?;
+
+        // A coin that was never marked has had nothing to fall short on
+        let collateral_ratio_bps = if sovereign_coin.collateral_marked_at == 0 {
+            u64::MAX
+        } else {
+            sovereign_coin.collateral_ratio_bps
+        };
+
+        let quote = quote_redeem(
+            sovereign_coin,
+            to_accrued(sovereign_amount)?,
+            redeem_fee_bps,
+            ctx.accounts.usdc_mint.decimals,
+            payment_price,
+            usdc_depeg_price,
+            collateral_ratio_bps,
+            user_share_of_fiat_reserve,
+            ctx.accounts.usdc_protocol_vault.amount,
+        )?;
+
+        Ok(PreviewRedeemResult {
+            sovereign_amount,
+            usdc_amount: quote.usdc_amount,
+            spread_amount: quote.spread_amount,
+            collateral_haircut: quote.collateral_haircut,
+            redeem_fee_bps,
+            protocol_fee: quote.protocol_fee,
+            net_amount: quote.net_amount,
+            from_usdc_reserve: quote.from_usdc_reserve,
+            from_protocol_vault: quote.from_protocol_vault,
+            from_bond_redemption: quote.from_bond_redemption,
+            redemption_type: quote.redemption_type(),
+            transfer_fee: token_extension::expected_transfer_fee(&mint_account_info, sovereign_amount)?,
+            oracle_rate: quote.oracle_rate,
+            applied_rate: quote.applied_rate,
+            usdc_depegged: usdc_depeg_price.is_some(),
+        })
+    }
+}
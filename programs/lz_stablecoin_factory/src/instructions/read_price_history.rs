@@ -0,0 +1,63 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReadPriceHistoryArgs {
+    pub window: i64,                      // Seconds to average over
+    pub max_deviation_bps: Option<u16>,   // Fail when the latest price is further than this from the TWAP
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceHistoryStats {
+    pub twap: i128,                 // Scaled by ORACLE_PRICE_SCALE, like the EMA and latest price
+    pub ema: i128,
+    pub latest_price: i128,
+    pub latest_slot: u64,
+    pub latest_timestamp: i64,
+    pub samples_in_window: u8,
+    pub deviation_bps: u64,         // Distance of the latest price from the TWAP
+}
+
+#[derive(Accounts)]
+pub struct ReadPriceHistory<'info> {
+    #[account(
+        seeds = [b"price_history", price_history.sovereign_coin.as_ref()],
+        bump = price_history.bump,
+    )]
+    pub price_history: Box<Account<'info, PriceHistory>>,
+}
+
+impl ReadPriceHistory<'_> {
+    pub fn handler(ctx: Context<Self>, args: ReadPriceHistoryArgs) -> Result<PriceHistoryStats> {
+        require!(args.window > 0, StablecoinError::InvalidPriceHistoryConfig);
+
+        let price_history = &ctx.accounts.price_history;
+        let now = Clock::get()?.unix_timestamp;
+
+        let latest = price_history.latest().ok_or(StablecoinError::InvalidPriceFeed)?;
+        let latest_price = latest.normalized_price()?;
+        let twap = price_history.twap(args.window, now)?.unwrap_or(latest_price);
+        // With no samples inside the window the last price is carried forward, as the TWAP does
+        let ema = price_history.ema(args.window, now)?.unwrap_or(twap);
+
+        require!(twap > 0, StablecoinError::InvalidPriceFeed);
+        let deviation_bps = latest_price.safe_sub(twap)?.unsigned_abs()
+            .safe_mul(BASIS_POINT_MAX as u128)?
+            .safe_div(twap.unsigned_abs())?;
+        if let Some(max_deviation_bps) = args.max_deviation_bps {
+            require!(
+                is_within_deviation(latest_price, twap, max_deviation_bps)?,
+                StablecoinError::PriceDeviatesFromTwap
+            );
+        }
+
+        Ok(PriceHistoryStats {
+            twap,
+            ema,
+            latest_price,
+            latest_slot: latest.slot,
+            latest_timestamp: latest.timestamp,
+            samples_in_window: price_history.count_in_window(args.window, now)?,
+            deviation_bps: u64::try_from(deviation_bps).map_err(|_| StablecoinError::MathError)?,
+        })
+    }
+}
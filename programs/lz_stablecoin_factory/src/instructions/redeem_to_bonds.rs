@@ -0,0 +1,245 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RedeemToBondsArgs {
+    pub sovereign_amount: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(args: RedeemToBondsArgs)]
+pub struct RedeemToBonds<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
+        constraint = !sovereign_coin.is_basket @ StablecoinError::BasketNotSupported,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CurrencyStats::INIT_SPACE,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"sovereign_coin_stats", sovereign_coin.key().as_ref()],
+        bump = sovereign_coin_stats.bump,
+    )]
+    pub sovereign_coin_stats: Box<Account<'info, SovereignCoinStats>>,
+
+    #[account(
+        mut,
+        seeds = [b"in_kind_redemption", sovereign_coin.key().as_ref()],
+        bump = in_kind_redemption_config.bump,
+    )]
+    pub in_kind_redemption_config: Box<Account<'info, InKindRedemptionConfig>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+        constraint = user_sovereign_coin_account.amount >= args.sovereign_amount @ StablecoinError::InsufficientBalance
+    )]
+    pub user_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+        constraint = bond_holding.mint == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redeemer's Stablebond account (to receive)
+    #[account(
+        mut,
+        token::mint = bond_token_mint,
+        token::authority = payer,
+    )]
+    pub user_bond_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Redeemer's USDC account for the reserve share
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = payer,
+    )]
+    pub user_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl RedeemToBonds<'_> {
+    /// Burn coins for their pro-rata share of the coin's bonds and USDC reserve. Nothing is sold,
+    /// so there is no oracle price, Etherfuse sell fee or slippage, and the holders left behind
+    /// keep the same backing per coin.
+    pub fn handler(ctx: Context<Self>, args: RedeemToBondsArgs) -> Result<()> {
+        ctx.accounts.currency_stats.track_coin(
+            ctx.bumps.currency_stats,
+            &mut ctx.accounts.sovereign_coin,
+            &mut ctx.accounts.factory,
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let sovereign_amount = args.sovereign_amount;
+        require!(sovereign_amount > 0, StablecoinError::InvalidAmount);
+
+        let clock = Clock::get()?;
+        let total_supply = ctx.accounts.sovereign_coin.total_supply;
+        require!(sovereign_amount <= total_supply, StablecoinError::InsufficientBalance);
+
+        // Yield claims accrue on the bond balance before this redemption changes it
+        ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+
+        // Part of the holding is the bonds' appreciation owed to the yield claims, it is not the holders'
+        // to take, so only what is left after the claim-equivalent bonds is split pro rata
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let unsettled_claims = sovereign_coin.unsettled_yield_claims()?;
+        let claim_bonds = if unsettled_claims > 0 {
+            mul_div(
+                ctx.accounts.bond_holding.amount,
+                unsettled_claims,
+                sovereign_coin.bond_amount.safe_add(unsettled_claims)?,
+                Rounding::Up,
+            )?
+        } else {
+            0
+        };
+        let holder_bonds = ctx.accounts.bond_holding.amount.safe_sub(claim_bonds)?;
+        let bond_amount = mul_div(holder_bonds, sovereign_amount, total_supply, Rounding::Down)?;
+        let bond_book_value = mul_div(sovereign_coin.bond_amount, sovereign_amount, total_supply, Rounding::Down)?;
+        let usdc_amount = mul_div(sovereign_coin.usdc_amount, sovereign_amount, total_supply, Rounding::Down)?;
+        require!(bond_amount > 0 || usdc_amount > 0, StablecoinError::InvalidAmount);
+
+        ctx.accounts.in_kind_redemption_config.record_redemption(sovereign_amount, bond_amount, clock.unix_timestamp)?;
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.user_sovereign_coin_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            sovereign_amount,
+        )?;
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+
+        if bond_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.bond_holding.to_account_info(),
+                        mint: ctx.accounts.bond_token_mint.to_account_info(),
+                        to: ctx.accounts.user_bond_account.to_account_info(),
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                bond_amount,
+                ctx.accounts.bond_token_mint.decimals,
+            )?;
+        }
+
+        if usdc_amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.global_usdc_reserve.to_account_info(),
+                        mint: ctx.accounts.usdc_mint.to_account_info(),
+                        to: ctx.accounts.user_usdc_token_account.to_account_info(),
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                usdc_amount,
+                ctx.accounts.usdc_mint.decimals,
+            )?;
+        }
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.total_supply = sovereign_coin.total_supply.safe_sub(sovereign_amount)?;
+        sovereign_coin.usdc_amount = sovereign_coin.usdc_amount.safe_sub(usdc_amount)?;
+        sovereign_coin.bond_amount = sovereign_coin.bond_amount.safe_sub(bond_book_value)?;
+
+        ctx.accounts.factory.decrease_total_supply(sovereign_amount)?;
+        ctx.accounts.factory.decrease_total_backing(usdc_amount.safe_add(bond_book_value)?)?;
+        ctx.accounts.currency_stats.record_redeem(
+            sovereign_amount,
+            usdc_amount,
+            bond_book_value,
+            0,
+            clock.unix_timestamp,
+        )?;
+        ctx.accounts.sovereign_coin_stats.record_in_kind_redeem(usdc_amount, clock.unix_timestamp)?;
+
+        emit_cpi!(SovereignCoinRedeemedToBondsEvent {
+            payer: ctx.accounts.payer.key(),
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            sovereign_amount,
+            bond_amount,
+            bond_book_value,
+            usdc_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
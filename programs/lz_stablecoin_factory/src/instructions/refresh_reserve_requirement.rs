@@ -0,0 +1,65 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefreshReserveRequirement<'info> {
+    // Anyone can bring a coin's stored requirement in line with the current policy
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = currency_stats.bump,
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        seeds = [b"reserve_policy"],
+        bump = reserve_policy.bump,
+    )]
+    pub reserve_policy: Box<Account<'info, ReservePolicy>>,
+}
+
+impl RefreshReserveRequirement<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let required_reserve_percentage = reserve::calculate_policy_reserve(
+            &ctx.accounts.reserve_policy,
+            &ctx.accounts.factory,
+            ctx.accounts.sovereign_coin.bond_rating,
+            ctx.accounts.currency_stats.backing_value()?,
+        )?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        let old_required_reserve_percentage = sovereign_coin.required_reserve_percentage;
+        sovereign_coin.required_reserve_percentage = required_reserve_percentage;
+
+        let clock = Clock::get()?;
+        emit_cpi!(ReserveRequirementRefreshedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            old_required_reserve_percentage,
+            new_required_reserve_percentage: required_reserve_percentage,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
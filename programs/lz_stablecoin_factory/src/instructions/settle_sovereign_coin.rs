@@ -0,0 +1,395 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleSovereignCoin<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.is_lifecycle_authority(&authority.key()) @ StablecoinError::Unauthorized,
+        constraint = sovereign_coin.lifecycle == SovereignCoinLifecycle::RedeemOnly @ StablecoinError::InvalidLifecycleTransition,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"currency_stats",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = currency_stats.bump,
+    )]
+    pub currency_stats: Box<Account<'info, CurrencyStats>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_account.key() == factory.global_usdc_account @ StablecoinError::InvalidGlobalUsdcAccount,
+    )]
+    pub global_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Receives the issuer's share of the yield still owed when the coin settles
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = sovereign_coin.creator,
+    )]
+    pub issuer_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Bonds held on behalf of the coin holders
+    #[account(
+        mut,
+        associated_token::mint = bond_token_mint,
+        associated_token::authority = factory,
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Bonds the protocol owns outright
+    #[account(
+        mut,
+        associated_token::mint = bond_token_mint,
+        associated_token::authority = factory,
+        constraint = bond_ownership.key() == sovereign_coin.bond_ownership @ StablecoinError::InvalidBondHolding,
+    )]
+    pub bond_ownership: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_token_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Required for basket coins, constituent (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> SettleSovereignCoin<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+
+        // Holder bonds are redeemed into the reserve backing the pro-rata claims
+        let (bonds_redeemed, holder_usdc_received) = if ctx.accounts.sovereign_coin.is_basket {
+            ctx.accounts.redeem_basket_to_reserve(ctx.remaining_accounts, factory_signer)?
+        } else {
+            let bonds_redeemed = ctx.accounts.bond_holding.amount;
+            let holder_usdc_received = if bonds_redeemed > 0 {
+                let bond_holding = ctx.accounts.bond_holding.to_account_info();
+                let global_usdc_reserve = ctx.accounts.global_usdc_reserve.to_account_info();
+                ctx.accounts.redeem_bonds_to(bond_holding, bonds_redeemed, global_usdc_reserve, factory_signer)?
+            } else {
+                0
+            };
+            (bonds_redeemed, holder_usdc_received)
+        };
+
+        // Protocol owned bonds are redeemed back into the protocol vault
+        let protocol_bonds_redeemed = ctx.accounts.bond_ownership.amount;
+        let protocol_usdc_received = if protocol_bonds_redeemed > 0 {
+            let bond_ownership = ctx.accounts.bond_ownership.to_account_info();
+            let usdc_protocol_vault = ctx.accounts.usdc_protocol_vault.to_account_info();
+            ctx.accounts.redeem_bonds_to(bond_ownership, protocol_bonds_redeemed, usdc_protocol_vault, factory_signer)?
+        } else {
+            0
+        };
+
+        // The yield claims are booked against the bonds that were just redeemed, they are paid
+        // out of the proceeds first and only the rest backs the holders' settlement claims
+        let clock = Clock::get()?;
+        let bond_book = ctx.accounts.sovereign_coin.bond_amount;
+        ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+
+        let protocol_claim = ctx.accounts.sovereign_coin.protocol_yield_accrued;
+        let issuer_claim = ctx.accounts.sovereign_coin.issuer_yield_accrued;
+        let holder_claim = ctx.accounts.sovereign_coin.holder_yield_accrued;
+        let total_claim = ctx.accounts.sovereign_coin.unsettled_yield_claims()?;
+
+        // Proceeds short of the claims are shared in proportion to them
+        let claims_paid = total_claim.min(holder_usdc_received);
+        let (protocol_yield_paid, holder_yield_paid) = if total_claim > 0 {
+            (
+                mul_div(claims_paid, protocol_claim, total_claim, Rounding::Down)?,
+                mul_div(claims_paid, holder_claim, total_claim, Rounding::Down)?,
+            )
+        } else {
+            (0, 0)
+        };
+        let issuer_yield_paid = claims_paid.safe_sub(protocol_yield_paid)?.safe_sub(holder_yield_paid)?;
+
+        let usdc_protocol_vault = ctx.accounts.usdc_protocol_vault.to_account_info();
+        ctx.accounts.pay_from_reserve(usdc_protocol_vault, protocol_yield_paid, factory_signer)?;
+        let issuer_usdc_token_account = ctx.accounts.issuer_usdc_token_account.to_account_info();
+        ctx.accounts.pay_from_reserve(issuer_usdc_token_account, issuer_yield_paid, factory_signer)?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        let usdc_amount_before = sovereign_coin.usdc_amount;
+        sovereign_coin.protocol_yield_accrued = 0;
+        sovereign_coin.issuer_yield_accrued = 0;
+        sovereign_coin.holder_yield_accrued = 0;
+        // Holder yield stays in the reserve as rewards the depositors can still claim
+        sovereign_coin.distribute_rewards(holder_yield_paid)?;
+        sovereign_coin.usdc_amount = sovereign_coin.usdc_amount
+            .safe_add(holder_usdc_received.safe_sub(claims_paid)?)?;
+        sovereign_coin.bond_amount = 0;
+        let settlement_usdc_added = sovereign_coin.usdc_amount.safe_sub(usdc_amount_before)?;
+
+        ctx.accounts.factory.decrease_total_backing(bond_book)?;
+        ctx.accounts.factory.increase_total_backing(settlement_usdc_added)?;
+        ctx.accounts.currency_stats.record_settlement(
+            bond_book,
+            settlement_usdc_added,
+            clock.unix_timestamp,
+        )?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.lifecycle = SovereignCoinLifecycle::Settled;
+        sovereign_coin.settled_at = clock.unix_timestamp;
+
+        emit_cpi!(SovereignCoinSettledEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            bonds_redeemed,
+            holder_usdc_received,
+            protocol_bonds_redeemed,
+            protocol_usdc_received,
+            protocol_yield_paid,
+            issuer_yield_paid,
+            holder_yield_paid,
+            settlement_usdc_amount: sovereign_coin.usdc_amount,
+            outstanding_supply: sovereign_coin.total_supply,
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit_cpi!(SovereignCoinLifecycleChangedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            authority: ctx.accounts.authority.key(),
+            old_lifecycle: SovereignCoinLifecycle::RedeemOnly,
+            new_lifecycle: SovereignCoinLifecycle::Settled,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Transfers `amount` USDC out of the global reserve to `destination`
+    fn pay_from_reserve(
+        &self,
+        destination: AccountInfo<'info>,
+        amount: u64,
+        factory_signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.global_usdc_reserve.to_account_info(),
+                    mint: self.usdc_token_mint.to_account_info(),
+                    to: destination,
+                    authority: self.factory.to_account_info(),
+                },
+                factory_signer,
+            ),
+            amount,
+            self.usdc_token_mint.decimals,
+        )
+    }
+
+    /// Instantly redeems `amount` bonds from `bond_source` and forwards the USDC proceeds to `destination`
+    fn redeem_bonds_to(
+        &mut self,
+        bond_source: AccountInfo<'info>,
+        amount: u64,
+        destination: AccountInfo<'info>,
+        factory_signer: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let global_usdc_balance_before = self.global_usdc_account.amount;
+
+        let payment_feed_type = self.sovereign_coin.get_payment_feed_type()?;
+        let (bond_pda, _) = find_bond_pda(self.bond_token_mint.key());
+        let (issuance_pda, _) = find_issuance_pda(bond_pda, self.sovereign_coin.bond_issuance_number);
+        let (payment_feed_pda, _) = find_payment_feed_pda(payment_feed_type);
+        let (sell_liquidity_pda, _) = find_sell_liquidity_pda(bond_pda);
+        let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &self.usdc_token_mint.key());
+        let fee_collector_wallet_token_account = get_associated_token_address(&ETHERFUSE_FEE_COLLECTOR, &self.usdc_token_mint.key());
+
+        let instant_redemption_ix = InstantBondRedemption {
+            user_wallet: self.factory.key(),
+            user_bond_token_account: bond_source.key(),
+            user_payment_token_account: self.global_usdc_account.key(),
+            bond_account: bond_pda,
+            mint_account: self.bond_token_mint.key(),
+            issuance_account: issuance_pda,
+            payment_mint_account: self.usdc_token_mint.key(),
+            payment_feed_account: payment_feed_pda,
+            sell_liquidity_account: sell_liquidity_pda,
+            sell_liquidity_token_account,
+            fee_collector_wallet_token_account,
+            payment_base_price_feed_account: self.factory.payment_base_price_feed_account,
+            payment_quote_price_feed_account: self.factory.payment_quote_price_feed_account,
+            associated_token_program: spl_associated_token_account::id(),
+            token_program: spl_token::id(),
+            token2022_program: spl_token_2022::id(),
+            system_program: solana_program::system_program::id(),
+        }
+        .instruction(InstantBondRedemptionInstructionArgs { amount });
+
+        solana_program::program::invoke_signed(
+            &instant_redemption_ix,
+            &[
+                self.factory.to_account_info(),
+                bond_source,
+                self.global_usdc_account.to_account_info(),
+                self.bond_token_mint.to_account_info(),
+                self.usdc_token_mint.to_account_info(),
+                self.token_program.to_account_info(),
+                self.token_2022_program.to_account_info(),
+                self.associated_token_program.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+            factory_signer,
+        )
+        .map_err(|_| StablecoinError::InstantRedemptionFailed)?;
+
+        self.global_usdc_account.reload()?;
+        let usdc_received = self.global_usdc_account.amount.safe_sub(global_usdc_balance_before)?;
+
+        if usdc_received > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.global_usdc_account.to_account_info(),
+                        mint: self.usdc_token_mint.to_account_info(),
+                        to: destination,
+                        authority: self.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                usdc_received,
+                self.usdc_token_mint.decimals,
+            )?;
+            self.global_usdc_account.reload()?;
+        }
+
+        Ok(usdc_received)
+    }
+
+    /// Instantly redeems every basket constituent and moves the USDC proceeds into the reserve,
+    /// returning the bonds redeemed and the USDC received
+    fn redeem_basket_to_reserve(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        factory_signer: &[&[&[u8]]],
+    ) -> Result<(u64, u64)> {
+        let global_usdc_balance_before = self.global_usdc_account.amount;
+        let bond_basket = self.bond_basket
+            .as_mut()
+            .ok_or(StablecoinError::BondBasketRequired)?;
+        let constituent_accounts = bond_basket.constituent_accounts(remaining_accounts)?;
+
+        let programs = StablebondPrograms {
+            system_program: &self.system_program.to_account_info(),
+            token_program: &self.token_program.to_account_info(),
+            token_2022_program: &self.token_2022_program.to_account_info(),
+            associated_token_program: &self.associated_token_program.to_account_info(),
+        };
+
+        let mut bonds_redeemed = 0u64;
+        for (i, (bond_mint, bond_holding)) in constituent_accounts.into_iter().enumerate() {
+            let amount = InterfaceAccount::<TokenAccount>::try_from(bond_holding)?.amount;
+            if amount > 0 {
+                instant_redeem_bond_signed(
+                    &self.factory,
+                    factory_signer,
+                    bond_holding,
+                    &self.global_usdc_account.to_account_info(),
+                    bond_mint,
+                    &self.usdc_token_mint.to_account_info(),
+                    &programs,
+                    bond_basket.constituents[i].get_payment_feed_type()?,
+                    bond_basket.constituents[i].bond_issuance_number,
+                    amount,
+                )?;
+                bonds_redeemed = bonds_redeemed.safe_add(amount)?;
+            }
+            bond_basket.constituents[i].bond_amount = 0;
+        }
+
+        self.global_usdc_account.reload()?;
+        let usdc_received = self.global_usdc_account.amount.safe_sub(global_usdc_balance_before)?;
+
+        if usdc_received > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.global_usdc_account.to_account_info(),
+                        mint: self.usdc_token_mint.to_account_info(),
+                        to: self.global_usdc_reserve.to_account_info(),
+                        authority: self.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                usdc_received,
+                self.usdc_token_mint.decimals,
+            )?;
+            self.global_usdc_account.reload()?;
+        }
+
+        Ok((bonds_redeemed, usdc_received))
+    }
+}
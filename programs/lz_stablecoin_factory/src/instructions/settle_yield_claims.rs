@@ -0,0 +1,267 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleYieldClaims<'info> {
+    // Anyone can settle, the proceeds only ever go to the protocol vault, the issuer and the holders' reward pool
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.accepts_redemptions() @ StablecoinError::RedemptionsClosed,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_account.key() == factory.global_usdc_account @ StablecoinError::InvalidGlobalUsdcAccount,
+    )]
+    pub global_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_token_mint,
+        associated_token::authority = sovereign_coin.creator,
+    )]
+    pub issuer_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = bond_token_mint,
+        associated_token::authority = factory,
+        constraint = bond_holding.key() == sovereign_coin.bond_holding @ StablecoinError::InvalidBondHolding,
+    )]
+    pub bond_holding: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_token_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = bond_token_mint.key() == sovereign_coin.bond_mint @ StablecoinError::InvalidBondMint
+    )]
+    pub bond_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Required for basket coins, constituent (bond mint, bond holding) pairs follow in the remaining accounts
+    #[account(
+        mut,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump = bond_basket.bump,
+    )]
+    pub bond_basket: Option<Box<Account<'info, BondBasket>>>,
+
+    /// CHECK: Oracle account
+    #[account(
+        constraint = payment_base_price_feed_account.key() == factory.payment_base_price_feed_account @ StablecoinError::InvalidPriceFeed
+    )]
+    pub payment_base_price_feed_account: UncheckedAccount<'info>,
+
+    /// CHECK: Quote oracle account
+    pub payment_quote_price_feed_account: Option<UncheckedAccount<'info>>,
+
+    // Set when the coin's currency is priced through a route, its leg feeds follow any basket pairs in the remaining accounts
+    #[account(
+        seeds = [
+            b"price_route",
+            &sovereign_coin.target_fiat_currency[..sovereign_coin.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = price_route.bump,
+    )]
+    pub price_route: Option<Box<Account<'info, PriceRoute>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> SettleYieldClaims<'info> {
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let clock = Clock::get()?;
+        ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+
+        let protocol_claim = ctx.accounts.sovereign_coin.protocol_yield_accrued;
+        let issuer_claim = ctx.accounts.sovereign_coin.issuer_yield_accrued;
+        let holder_claim = ctx.accounts.sovereign_coin.holder_yield_accrued;
+        let total_claim = ctx.accounts.sovereign_coin.unsettled_yield_claims()?;
+        require!(total_claim > 0, StablecoinError::NoYieldToSettle);
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+
+        let programs = StablebondPrograms {
+            system_program: &ctx.accounts.system_program.to_account_info(),
+            token_program: &ctx.accounts.token_program.to_account_info(),
+            token_2022_program: &ctx.accounts.token_2022_program.to_account_info(),
+            associated_token_program: &ctx.accounts.associated_token_program.to_account_info(),
+        };
+        let global_usdc_balance_before = ctx.accounts.global_usdc_account.amount;
+
+        // The claims are booked in USDC, the redemption is sized in bond tokens at the price Etherfuse pays out at
+        let base_account_info = ctx.accounts.payment_base_price_feed_account.to_account_info();
+        let quote_account_info = ctx.accounts.payment_quote_price_feed_account.as_ref()
+            .map(|acc| acc.to_account_info());
+        let basket_accounts_len = match ctx.accounts.bond_basket.as_deref() {
+            Some(bond_basket) if ctx.accounts.sovereign_coin.is_basket => bond_basket.constituent_count as usize * 2,
+            _ => 0,
+        };
+        let route_feed_accounts = ctx.remaining_accounts.get(basket_accounts_len..).unwrap_or(&[]);
+        let payment_price = switchboard::resolve_payment_price(
+            &base_account_info,
+            quote_account_info.as_ref(),
+            ctx.accounts.price_route.as_deref().map(|route| (route, route_feed_accounts)),
+            ctx.accounts.sovereign_coin.target_currency()?,
+        )?;
+
+        // The claims are the bonds' appreciation, so they come out of the holding without touching the principal
+        if ctx.accounts.sovereign_coin.is_basket {
+            let bond_basket = ctx.accounts.bond_basket
+                .as_mut()
+                .ok_or(StablecoinError::BondBasketRequired)?;
+            // The holdings are worth the bond book plus the unsettled claims on top of it
+            let holdings_value = ctx.accounts.sovereign_coin.bond_amount.safe_add(total_claim)?;
+            let allocations = bond_basket.split_by_holdings(total_claim, holdings_value)?;
+
+            for (i, (bond_mint, bond_holding)) in bond_basket
+                .constituent_accounts(ctx.remaining_accounts)?
+                .into_iter()
+                .enumerate()
+            {
+                let bond_tokens = valuation::bond_amount_for_value(
+                    bond_mint,
+                    InterfaceAccount::<Mint>::try_from(bond_mint)?.decimals,
+                    allocations[i],
+                    payment_price,
+                    clock.unix_timestamp,
+                )?;
+                if bond_tokens == 0 {
+                    continue;
+                }
+
+                instant_redeem_bond_signed(
+                    &ctx.accounts.factory,
+                    factory_signer,
+                    bond_holding,
+                    &ctx.accounts.global_usdc_account.to_account_info(),
+                    bond_mint,
+                    &ctx.accounts.usdc_token_mint.to_account_info(),
+                    &programs,
+                    bond_basket.constituents[i].get_payment_feed_type()?,
+                    bond_basket.constituents[i].bond_issuance_number,
+                    bond_tokens,
+                )?;
+
+                let constituent = &mut bond_basket.constituents[i];
+                constituent.bond_amount = constituent.bond_amount.safe_sub(allocations[i])?;
+            }
+        } else {
+            let bond_tokens = valuation::bond_amount_for_value(
+                &ctx.accounts.bond_token_mint.to_account_info(),
+                ctx.accounts.bond_token_mint.decimals,
+                total_claim,
+                payment_price,
+                clock.unix_timestamp,
+            )?;
+
+            instant_redeem_bond_signed(
+                &ctx.accounts.factory,
+                factory_signer,
+                &ctx.accounts.bond_holding.to_account_info(),
+                &ctx.accounts.global_usdc_account.to_account_info(),
+                &ctx.accounts.bond_token_mint.to_account_info(),
+                &ctx.accounts.usdc_token_mint.to_account_info(),
+                &programs,
+                ctx.accounts.sovereign_coin.get_payment_feed_type()?,
+                ctx.accounts.sovereign_coin.bond_issuance_number,
+                bond_tokens,
+            )?;
+        }
+
+        ctx.accounts.global_usdc_account.reload()?;
+        let usdc_received = ctx.accounts.global_usdc_account.amount.safe_sub(global_usdc_balance_before)?;
+
+        // Redemption fees are shared in proportion to the claims
+        let protocol_paid = mul_div(usdc_received, protocol_claim, total_claim, Rounding::Down)?;
+        let holder_paid = mul_div(usdc_received, holder_claim, total_claim, Rounding::Down)?;
+        let issuer_paid = usdc_received.safe_sub(protocol_paid)?.safe_sub(holder_paid)?;
+
+        for (destination, amount) in [
+            (ctx.accounts.usdc_protocol_vault.to_account_info(), protocol_paid),
+            (ctx.accounts.issuer_usdc_token_account.to_account_info(), issuer_paid),
+            (ctx.accounts.global_usdc_reserve.to_account_info(), holder_paid),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.global_usdc_account.to_account_info(),
+                        mint: ctx.accounts.usdc_token_mint.to_account_info(),
+                        to: destination,
+                        authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                amount,
+                ctx.accounts.usdc_token_mint.decimals,
+            )?;
+        }
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.protocol_yield_accrued = 0;
+        sovereign_coin.issuer_yield_accrued = 0;
+        sovereign_coin.holder_yield_accrued = 0;
+        sovereign_coin.distribute_rewards(holder_paid)?;
+
+        emit_cpi!(YieldClaimsSettledEvent {
+            sovereign_coin: sovereign_coin.key(),
+            keeper: ctx.accounts.keeper.key(),
+            protocol_claim,
+            issuer_claim,
+            holder_claim,
+            protocol_paid,
+            issuer_paid,
+            holder_paid,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
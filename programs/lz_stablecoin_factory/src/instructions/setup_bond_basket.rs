@@ -0,0 +1,166 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BasketConstituentArgs {
+    pub bond_mint: Pubkey,
+    pub bond_issuance_number: u64,
+    pub weight_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetupBondBasketArgs {
+    // The first constituent must be the coin's own bond, its issuance comes from setup_bond_info
+    pub constituents: Vec<BasketConstituentArgs>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetupBondBasket<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.creator == creator.key() @ StablecoinError::Unauthorized,
+        constraint = sovereign_coin.is_active() @ StablecoinError::SovereignCoinNotActive,
+        constraint = sovereign_coin.bond_holding != Pubkey::default() @ StablecoinError::InvalidBondHolding,
+        constraint = sovereign_coin.total_supply == 0 && sovereign_coin.bond_amount == 0 @ StablecoinError::BasketAlreadyInUse,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + BondBasket::INIT_SPACE,
+        seeds = [b"bond_basket", sovereign_coin.key().as_ref()],
+        bump,
+    )]
+    pub bond_basket: Box<Account<'info, BondBasket>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SetupBondBasket<'_> {
+    /// Remaining accounts: a (bond holding, Stablebond bond account) pair for every constituent, in order.
+    /// Holdings for the extra bonds are plain associated token accounts created beforehand.
+    pub fn handler(ctx: Context<Self>, args: SetupBondBasketArgs) -> Result<()> {
+        let constituents = &args.constituents;
+        require!(
+            !constituents.is_empty() && constituents.len() <= MAX_BOND_MAPPINGS,
+            StablecoinError::InvalidBasketWeights
+        );
+        require!(
+            ctx.remaining_accounts.len() == constituents.len() * 2,
+            StablecoinError::InvalidBasketAccounts
+        );
+        require_keys_eq!(
+            constituents[0].bond_mint,
+            ctx.accounts.sovereign_coin.bond_mint,
+            StablecoinError::InvalidBondMint
+        );
+        require_keys_eq!(
+            ctx.remaining_accounts[0].key(),
+            ctx.accounts.sovereign_coin.bond_holding,
+            StablecoinError::InvalidBondHolding
+        );
+
+        let factory = &ctx.accounts.factory;
+        let registered_mappings = &factory.bond_mappings[..factory.bond_mappings_count as usize];
+        let target_fiat_currency = ctx.accounts.sovereign_coin.target_fiat_currency;
+        let mut payment_feed_types = [0u8; MAX_BOND_MAPPINGS];
+        let mut total_weight_bps: u16 = 0;
+
+        for (i, constituent) in constituents.iter().enumerate() {
+            require!(constituent.weight_bps > 0, StablecoinError::InvalidBasketWeights);
+            total_weight_bps = total_weight_bps.safe_add(constituent.weight_bps)?;
+
+            // Every constituent pays out in the coin's currency, so one oracle price marks the whole basket
+            require!(
+                registered_mappings.iter().any(|mapping| {
+                    mapping.active
+                        && mapping.bond_mint == constituent.bond_mint
+                        && mapping.fiat_currency == target_fiat_currency
+                }),
+                StablecoinError::InvalidBondMint
+            );
+            require!(
+                !constituents[..i].iter().any(|other| other.bond_mint == constituent.bond_mint),
+                StablecoinError::InvalidBondMint
+            );
+
+            let bond_holding = &ctx.remaining_accounts[i * 2];
+            require_keys_eq!(
+                bond_holding.key(),
+                get_associated_token_address(&factory.key(), &constituent.bond_mint),
+                StablecoinError::InvalidBondHolding
+            );
+            require!(
+                !bond_holding.data_is_empty(),
+                StablecoinError::InvalidBondHolding
+            );
+
+            // Each bond is bought and sold through its own Etherfuse payment feed
+            let bond_account = &ctx.remaining_accounts[i * 2 + 1];
+            require_keys_eq!(
+                bond_account.key(),
+                find_bond_pda(constituent.bond_mint).0,
+                StablecoinError::InvalidBondAccount
+            );
+            let bond = Bond::from_bytes(&bond_account.try_borrow_data()?)
+                .map_err(|_| StablecoinError::InvalidBondAccountData)?;
+            payment_feed_types[i] = SovereignCoin::payment_feed_type_to_u8(bond.payment_feed_type);
+        }
+
+        require!(
+            total_weight_bps == BASIS_POINT_MAX,
+            StablecoinError::InvalidBasketWeights
+        );
+
+        let bond_basket = &mut ctx.accounts.bond_basket;
+        bond_basket.bump = ctx.bumps.bond_basket;
+        bond_basket.sovereign_coin = ctx.accounts.sovereign_coin.key();
+        bond_basket.constituent_count = constituents.len() as u8;
+        bond_basket.constituents = [BasketConstituent::default(); MAX_BOND_MAPPINGS];
+        for (i, constituent) in constituents.iter().enumerate() {
+            bond_basket.constituents[i] = BasketConstituent {
+                bond_mint: constituent.bond_mint,
+                bond_holding: ctx.remaining_accounts[i * 2].key(),
+                bond_issuance_number: if i == 0 {
+                    ctx.accounts.sovereign_coin.bond_issuance_number
+                } else {
+                    constituent.bond_issuance_number
+                },
+                weight_bps: constituent.weight_bps,
+                bond_amount: 0,
+                payment_feed_type: payment_feed_types[i],
+            };
+        }
+
+        // A single constituent basket is just the plain single bond coin
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.is_basket = constituents.len() > 1;
+
+        let clock = Clock::get()?;
+        emit_cpi!(BondBasketConfiguredEvent {
+            sovereign_coin: sovereign_coin.key(),
+            creator: ctx.accounts.creator.key(),
+            bond_mints: constituents.iter().map(|c| c.bond_mint).collect(),
+            weights_bps: constituents.iter().map(|c| c.weight_bps).collect(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
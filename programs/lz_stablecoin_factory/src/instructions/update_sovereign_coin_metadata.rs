@@ -0,0 +1,168 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateSovereignCoinMetadataArgs {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub image: Option<String>, // Only stored on-chain for Token-2022 metadata coins
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateSovereignCoinMetadata<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+        constraint = sovereign_coin.creator == issuer.key() @ StablecoinError::Unauthorized
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Metaplex metadata PDA, only required for coins without Token-2022 metadata
+    #[account(
+        mut,
+        constraint = metadata.key() == MetadataMpl::find_pda(&mint.key()).0 @ StablecoinError::InvalidMetadataAccount
+    )]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl UpdateSovereignCoinMetadata<'_> {
+    pub fn validate(&self, args: &UpdateSovereignCoinMetadataArgs) -> Result<()> {
+        require!(
+            args.name.is_some() || args.uri.is_some() || args.image.is_some(),
+            StablecoinError::EmptyMetadataUpdate
+        );
+
+        if let Some(name) = &args.name {
+            require!(!name.is_empty(), StablecoinError::InvalidNameLength);
+            require!(name.len() <= 32, StablecoinError::NameTooLong);
+        }
+        if let Some(uri) = &args.uri {
+            require!(uri.len() <= 200, StablecoinError::UriTooLong);
+        }
+        if let Some(image) = &args.image {
+            require!(image.len() <= 200, StablecoinError::UriTooLong);
+            require!(
+                self.sovereign_coin.uses_token_metadata,
+                StablecoinError::TokenMetadataRequired
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn handler(ctx: Context<Self>, args: UpdateSovereignCoinMetadataArgs) -> Result<()> {
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+
+        if ctx.accounts.sovereign_coin.uses_token_metadata {
+            let updates = [
+                (Field::Name, args.name.clone()),
+                (Field::Uri, args.uri.clone()),
+                (Field::Key("image".to_string()), args.image.clone()),
+            ];
+
+            for (field, value) in updates {
+                if let Some(value) = value {
+                    token_extension::update_token_metadata_field_signed(
+                        &ctx.accounts.issuer,
+                        &ctx.accounts.factory.to_account_info(),
+                        factory_signer,
+                        &ctx.accounts.mint.to_account_info(),
+                        &ctx.accounts.token_2022_program,
+                        &ctx.accounts.system_program,
+                        field,
+                        value,
+                    )?;
+                }
+            }
+        } else {
+            let metadata = ctx.accounts.metadata
+                .as_ref()
+                .ok_or(StablecoinError::InvalidMetadataAccount)?;
+            let token_metadata_program = ctx.accounts.token_metadata_program
+                .as_ref()
+                .ok_or(StablecoinError::InvalidMetadataAccount)?;
+
+            let sovereign_coin = &ctx.accounts.sovereign_coin;
+            let (current_name, symbol, current_uri) = sovereign_coin.metadata_strings()?;
+
+            // Metaplex replaces the whole data struct, so carry the unchanged fields over
+            update_metadata_accounts_v2(
+                CpiContext::new_with_signer(
+                    token_metadata_program.to_account_info(),
+                    UpdateMetadataAccountsV2 {
+                        metadata: metadata.to_account_info(),
+                        update_authority: ctx.accounts.factory.to_account_info(),
+                    },
+                    factory_signer,
+                ),
+                None,
+                Some(DataV2 {
+                    name: args.name.clone().unwrap_or(current_name),
+                    symbol,
+                    uri: args.uri.clone().unwrap_or(current_uri),
+                    seller_fee_basis_points: 0,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                }),
+                None,
+                None,
+            )?;
+        }
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+
+        if let Some(name) = &args.name {
+            let name_bytes = name.as_bytes();
+            sovereign_coin.name = [0u8; 32];
+            sovereign_coin.name[..name_bytes.len()].copy_from_slice(name_bytes);
+        }
+
+        if let Some(uri) = &args.uri {
+            let uri_bytes = uri.as_bytes();
+            sovereign_coin.uri = vec![0u8; 200];
+            sovereign_coin.uri[..uri_bytes.len()].copy_from_slice(uri_bytes);
+        }
+
+        let clock = Clock::get()?;
+        emit_cpi!(SovereignCoinMetadataUpdatedEvent {
+            issuer: ctx.accounts.issuer.key(),
+            sovereign_coin: sovereign_coin.key(),
+            mint: sovereign_coin.mint,
+            name: args.name,
+            uri: args.uri,
+            image: args.image,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawRewardDeposit<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    // Withdrawals stay open in every mode and lifecycle stage so deposits are never stuck
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        seeds = [b"holder_rewards", sovereign_coin.key().as_ref(), holder.key().as_ref()],
+        bump = holder_rewards.bump,
+    )]
+    pub holder_rewards: Box<Account<'info, HolderRewards>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub holder_sovereign_coin_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = sovereign_coin,
+        associated_token::token_program = token_2022_program,
+    )]
+    pub rewards_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = mint.key() == sovereign_coin.mint @ StablecoinError::InvalidSovereignCoinMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+impl WithdrawRewardDeposit<'_> {
+    pub fn handler(ctx: Context<Self>, amount: u64) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidAmount);
+        require!(
+            amount <= ctx.accounts.holder_rewards.deposited,
+            StablecoinError::InsufficientRewardDeposit
+        );
+
+        let clock = Clock::get()?;
+        ctx.accounts.sovereign_coin.accrue_yield_claims(&ctx.accounts.factory, clock.unix_timestamp)?;
+        ctx.accounts.holder_rewards.checkpoint(ctx.accounts.sovereign_coin.reward_index)?;
+
+        let sovereign_coin = &ctx.accounts.sovereign_coin;
+        let factory_key = ctx.accounts.factory.key();
+        let sovereign_coin_seeds = &[
+            b"sovereign_coin".as_ref(),
+            factory_key.as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)],
+            &[sovereign_coin.bump],
+        ];
+        let sovereign_coin_signer = &[&sovereign_coin_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_2022_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.rewards_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.holder_sovereign_coin_account.to_account_info(),
+                    authority: ctx.accounts.sovereign_coin.to_account_info(),
+                },
+                sovereign_coin_signer,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        let holder_rewards = &mut ctx.accounts.holder_rewards;
+        holder_rewards.deposited = holder_rewards.deposited.safe_sub(amount)?;
+
+        let sovereign_coin = &mut ctx.accounts.sovereign_coin;
+        sovereign_coin.reward_deposits = sovereign_coin.reward_deposits.safe_sub(amount)?;
+
+        emit_cpi!(RewardDepositChangedEvent {
+            sovereign_coin: sovereign_coin.key(),
+            holder: ctx.accounts.holder.key(),
+            deposited: -(amount as i64),
+            holder_deposit: holder_rewards.deposited,
+            total_deposits: sovereign_coin.reward_deposits,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawSpreadRevenue<'info> {
+    // Anyone can trigger the payout, it only ever goes to the coin's configured recipient
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [b"factory"],
+        bump = factory.bump,
+    )]
+    pub factory: Box<Account<'info, Factory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"sovereign_coin",
+            factory.key().as_ref(),
+            &sovereign_coin.symbol[..sovereign_coin.symbol.iter().position(|&x| x == 0).unwrap_or(8)]
+        ],
+        bump = sovereign_coin.bump,
+    )]
+    pub sovereign_coin: Box<Account<'info, SovereignCoin>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = global_usdc_reserve.key() == factory.global_usdc_reserve @ StablecoinError::InvalidGlobalUsdcReserve
+    )]
+    pub global_usdc_reserve: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = factory,
+        constraint = usdc_protocol_vault.key() == factory.protocol_vault @ StablecoinError::InvalidProtocolVault
+    )]
+    pub usdc_protocol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = sovereign_coin.creator,
+    )]
+    pub issuer_usdc_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == USDC_MINT @ StablecoinError::InvalidUSDCMint
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl WithdrawSpreadRevenue<'_> {
+    pub fn handler(ctx: Context<Self>) -> Result<()> {
+        let amount = ctx.accounts.sovereign_coin.spread_revenue_accrued;
+        require!(amount > 0, StablecoinError::NoSpreadRevenue);
+
+        let spread_recipient = ctx.accounts.sovereign_coin.spread_recipient;
+        let recipient = match spread_recipient {
+            SpreadRecipient::Protocol => ctx.accounts.usdc_protocol_vault.to_account_info(),
+            SpreadRecipient::Issuer => ctx.accounts.issuer_usdc_token_account.to_account_info(),
+        };
+
+        let factory_seeds = &[
+            b"factory".as_ref(),
+            &[ctx.accounts.factory.bump],
+        ];
+        let factory_signer = &[&factory_seeds[..]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.global_usdc_reserve.to_account_info(),
+                    mint: ctx.accounts.usdc_mint.to_account_info(),
+                    to: recipient.clone(),
+                    authority: ctx.accounts.factory.to_account_info(),
+                },
+                factory_signer,
+            ),
+            amount,
+            ctx.accounts.usdc_mint.decimals,
+        )?;
+
+        ctx.accounts.sovereign_coin.spread_revenue_accrued = 0;
+
+        let clock = Clock::get()?;
+        emit_cpi!(SpreadRevenueWithdrawnEvent {
+            sovereign_coin: ctx.accounts.sovereign_coin.key(),
+            recipient: recipient.key(),
+            spread_recipient,
+            amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
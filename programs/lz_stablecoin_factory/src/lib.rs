@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
 use switchboard_on_demand::PullFeedAccountData;
-use anchor_lang::system_program::{CreateAccount, create_account};
+use anchor_lang::system_program::{CreateAccount, create_account, Transfer, transfer};
 use anchor_lang::solana_program::{
     self, 
     // system_program, 
     msg
 };
 use anchor_spl::metadata::{
-    create_metadata_accounts_v3, 
-    mpl_token_metadata::types::DataV2, 
+    create_metadata_accounts_v3,
+    update_metadata_accounts_v2,
+    mpl_token_metadata::types::DataV2,
     CreateMetadataAccountsV3,
+    UpdateMetadataAccountsV2,
     Metadata,
 };
 use anchor_spl::{
@@ -28,6 +30,8 @@ use anchor_spl::{
         TokenAccount, 
         TransferChecked, 
         TransferCheckedWithFee,
+        CloseAccount,
+        close_account,
         spl_token_2022, 
         spl_pod::optional_keys::OptionalNonZeroPubkey, 
         transfer_checked_with_fee,
@@ -42,8 +46,15 @@ use anchor_spl::{
         // TransferFeeSetTransferFee,
         InterestBearingMintInitialize,
         InterestBearingMintUpdateRate,
-        interest_bearing_mint_initialize, 
+        interest_bearing_mint_initialize,
         interest_bearing_mint_update_rate,
+        MetadataPointerInitialize,
+        TokenMetadataInitialize,
+        TokenMetadataUpdateField,
+        metadata_pointer_initialize,
+        token_metadata_initialize,
+        token_metadata_update_field,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
     },
     token::{self, spl_token},
     token_2022::{
@@ -192,12 +203,19 @@ pub mod lz_stablecoin_factory {
         RegisterBondMapping::handler(ctx, fiat_currency, bond_mint, bond_rating)
     }
 
-    // pub fn preview_exchange(
-    //     ctx: Context<PreviewExchange>,
-    //     args: PreviewExchangeArgs,
-    // ) -> Result<PreviewExchangeResult> {
-    //     PreviewExchange::handler(ctx, args)
-    // }
+    pub fn preview_mint(
+        ctx: Context<PreviewMint>,
+        args: PreviewMintArgs,
+    ) -> Result<PreviewMintResult> {
+        PreviewMint::handler(ctx, args)
+    }
+
+    pub fn preview_redeem(
+        ctx: Context<PreviewRedeem>,
+        args: PreviewRedeemArgs,
+    ) -> Result<PreviewRedeemResult> {
+        PreviewRedeem::handler(ctx, args)
+    }
 
     pub fn update_interest_rate(
         ctx: Context<UpdateInterestRate>,
@@ -206,6 +224,142 @@ pub mod lz_stablecoin_factory {
         handle_update_interest_rate(ctx, manual_rate)
     }
 
+    pub fn configure_interest_rate_crank(
+        ctx: Context<ConfigureInterestRateCrank>,
+        args: ConfigureInterestRateCrankArgs,
+    ) -> Result<()> {
+        handle_configure_interest_rate_crank(ctx, args)
+    }
+
+    pub fn configure_fee_curve(
+        ctx: Context<ConfigureFeeCurve>,
+        args: ConfigureFeeCurveArgs,
+    ) -> Result<()> {
+        handle_configure_fee_curve(ctx, args)
+    }
+
+    pub fn set_currency_tax_rate(
+        ctx: Context<SetCurrencyTaxRate>,
+        fiat_currency: String,
+        tax_withholding_bps: Option<u16>,
+    ) -> Result<()> {
+        handle_set_currency_tax_rate(ctx, fiat_currency, tax_withholding_bps)
+    }
+
+    /// Permissionless keeper update of a coin's interest rate from its bond
+    pub fn crank_interest_rate(ctx: Context<CrankInterestRate>) -> Result<()> {
+        CrankInterestRate::handler(ctx)
+    }
+
+    pub fn setup_bond_basket(ctx: Context<SetupBondBasket>, args: SetupBondBasketArgs) -> Result<()> {
+        SetupBondBasket::handler(ctx, args)
+    }
+
+    pub fn settle_yield_claims<'info>(ctx: Context<'_, '_, 'info, 'info, SettleYieldClaims<'info>>) -> Result<()> {
+        SettleYieldClaims::handler(ctx)
+    }
+
+    pub fn deposit_for_rewards(ctx: Context<DepositForRewards>, amount: u64) -> Result<()> {
+        DepositForRewards::handler(ctx, amount)
+    }
+
+    pub fn withdraw_reward_deposit(ctx: Context<WithdrawRewardDeposit>, amount: u64) -> Result<()> {
+        WithdrawRewardDeposit::handler(ctx, amount)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        ClaimRewards::handler(ctx)
+    }
+
+    pub fn migrate_yield_mode(
+        ctx: Context<MigrateYieldMode>,
+        yield_mode: YieldMode,
+        reward_payout: RewardPayout,
+    ) -> Result<()> {
+        handle_migrate_yield_mode(ctx, yield_mode, reward_payout)
+    }
+
+    /// Grow the factory created before the upgrade to the current layout, run once before any coin is migrated
+    pub fn migrate_factory(ctx: Context<MigrateFactory>) -> Result<()> {
+        handle_migrate_factory(ctx)
+    }
+
+    /// Grow a coin created before the upgrade to the current layout and count it into the currency
+    /// and factory totals, run once per coin after migrate_factory
+    pub fn migrate_sovereign_coin(ctx: Context<MigrateSovereignCoin>, fiat_currency: [u8; 8]) -> Result<()> {
+        handle_migrate_sovereign_coin(ctx, fiat_currency)
+    }
+
+    pub fn configure_reserve_policy(
+        ctx: Context<ConfigureReservePolicy>,
+        args: ConfigureReservePolicyArgs,
+    ) -> Result<()> {
+        handle_configure_reserve_policy(ctx, args)
+    }
+
+    pub fn refresh_reserve_requirement(ctx: Context<RefreshReserveRequirement>) -> Result<()> {
+        RefreshReserveRequirement::handler(ctx)
+    }
+
+    pub fn configure_fx_spread(ctx: Context<ConfigureFxSpread>, args: ConfigureFxSpreadArgs) -> Result<()> {
+        ConfigureFxSpread::handler(ctx, args)
+    }
+
+    pub fn withdraw_spread_revenue(ctx: Context<WithdrawSpreadRevenue>) -> Result<()> {
+        WithdrawSpreadRevenue::handler(ctx)
+    }
+
+    /// Register the key an issuer signs firm RFQ quotes for its coin with and how far from the oracle they may quote
+    pub fn configure_rfq_signer(ctx: Context<ConfigureRfqSigner>, args: ConfigureRfqSignerArgs) -> Result<()> {
+        ConfigureRfqSigner::handler(ctx, args)
+    }
+
+    pub fn configure_usdc_peg_guard(
+        ctx: Context<ConfigureUsdcPegGuard>,
+        args: ConfigureUsdcPegGuardArgs,
+    ) -> Result<()> {
+        handle_configure_usdc_peg_guard(ctx, args)
+    }
+
+    /// Permissionless USDC/USD peg check, trips or clears the depeg circuit breaker
+    pub fn check_usdc_peg(ctx: Context<CheckUsdcPeg>) -> Result<()> {
+        CheckUsdcPeg::handler(ctx)
+    }
+
+    pub fn configure_dynamic_fees(
+        ctx: Context<ConfigureDynamicFees>,
+        args: ConfigureDynamicFeesArgs,
+    ) -> Result<()> {
+        handle_configure_dynamic_fees(ctx, args)
+    }
+
+    pub fn configure_price_route(
+        ctx: Context<ConfigurePriceRoute>,
+        args: ConfigurePriceRouteArgs,
+    ) -> Result<()> {
+        handle_configure_price_route(ctx, args)
+    }
+
+    pub fn configure_price_history(
+        ctx: Context<ConfigurePriceHistory>,
+        args: ConfigurePriceHistoryArgs,
+    ) -> Result<()> {
+        handle_configure_price_history(ctx, args)
+    }
+
+    /// Permissionless oracle sample into a coin's price history
+    pub fn crank_price_history(ctx: Context<CrankPriceHistory>) -> Result<()> {
+        CrankPriceHistory::handler(ctx)
+    }
+
+    /// TWAP and EMA of a coin's recorded oracle prices over a window
+    pub fn read_price_history(
+        ctx: Context<ReadPriceHistory>,
+        args: ReadPriceHistoryArgs,
+    ) -> Result<PriceHistoryStats> {
+        ReadPriceHistory::handler(ctx, args)
+    }
+
     pub fn create_fee_operator(ctx: Context<CreateFeeOperatorCtx>) -> Result<()> {
         handle_create_fee_operator(ctx)
     }
@@ -258,6 +412,35 @@ pub mod lz_stablecoin_factory {
         SetupInterestBearingMintWithTransferFee::handler(ctx, initial_rate, transfer_fee_basis_points, maximum_fee)
     }
 
+    pub fn setup_ibt_with_token_metadata(ctx: Context<SetupInterestBearingMintWithTokenMetadata>, initial_rate: i16, transfer_fee_basis_points: u16, maximum_fee: u64,) -> Result<()> {
+        SetupInterestBearingMintWithTokenMetadata::handler(ctx, initial_rate, transfer_fee_basis_points, maximum_fee)
+    }
+
+    /// Update the name, URI or image of a sovereign coin (issuer only)
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn update_sovereign_coin_metadata(ctx: Context<UpdateSovereignCoinMetadata>, args: UpdateSovereignCoinMetadataArgs) -> Result<()> {
+        UpdateSovereignCoinMetadata::handler(ctx, args)
+    }
+
+    /// Stop minting and cross-chain sends, holders can still redeem
+    pub fn enter_redeem_only(ctx: Context<EnterRedeemOnly>) -> Result<()> {
+        EnterRedeemOnly::handler(ctx)
+    }
+
+    /// Redeem all remaining bonds into USDC, pay the yield still owed and leave the rest for pro-rata claims
+    pub fn settle_sovereign_coin<'info>(ctx: Context<'_, '_, 'info, 'info, SettleSovereignCoin<'info>>) -> Result<()> {
+        SettleSovereignCoin::handler(ctx)
+    }
+
+    pub fn claim_settlement(ctx: Context<ClaimSettlement>, args: ClaimSettlementArgs) -> Result<()> {
+        ClaimSettlement::handler(ctx, args)
+    }
+
+    /// Close a settled sovereign coin once every coin is claimed, paying out its spread revenue and reclaiming its rent
+    pub fn close_sovereign_coin(ctx: Context<CloseSovereignCoin>) -> Result<()> {
+        CloseSovereignCoin::handler(ctx)
+    }
+
     pub fn setup_usdc_accounts(ctx: Context<SetupGlobalUsdcAccounts>) -> Result<()> {
         SetupGlobalUsdcAccounts::handler(ctx)
     }
@@ -270,15 +453,20 @@ pub mod lz_stablecoin_factory {
         FinalizeSetup::handler(ctx)
     }
 
-    pub fn initialize_mint_sovereign_coin(ctx: Context<InitializeMintSovereignCoin>, args: InitializeMintSovereignCoinArgs) -> Result<()> {
+    pub fn initialize_mint_sovereign_coin<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeMintSovereignCoin<'info>>, args: InitializeMintSovereignCoinArgs) -> Result<()> {
         InitializeMintSovereignCoin::handler(ctx, args)
     }
 
-    pub fn execute_mint_sovereign_coin(ctx: Context<ExecuteMintSovereignCoin>) -> Result<()> {
+    pub fn execute_mint_sovereign_coin<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteMintSovereignCoin<'info>>) -> Result<()> {
         ExecuteMintSovereignCoin::handler(ctx)
     }
 
-    pub fn initialize_redeem_sovereign_coin(ctx: Context<InitializeRedeemStablecoin>, args: InitializeRedeemStablecoinArgs) -> Result<()> {
+    /// Mint against Stablebonds deposited in kind, with a USDC top-up for the reserve
+    pub fn mint_with_bonds<'info>(ctx: Context<'_, '_, 'info, 'info, MintWithBonds<'info>>, args: MintWithBondsArgs) -> Result<()> {
+        MintWithBonds::handler(ctx, args)
+    }
+
+    pub fn initialize_redeem_sovereign_coin<'info>(ctx: Context<'_, '_, 'info, 'info, InitializeRedeemStablecoin<'info>>, args: InitializeRedeemStablecoinArgs) -> Result<()> {
         InitializeRedeemStablecoin::handler(ctx, args)
     }
 
@@ -290,10 +478,22 @@ pub mod lz_stablecoin_factory {
         ExecuteRedeemFromFiatAndProtocol::handler(ctx)
     }
 
-    pub fn execute_instant_redeem(ctx: Context<ExecuteInstantRedemption>) -> Result<()> {
+    pub fn execute_instant_redeem<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteInstantRedemption<'info>>) -> Result<()> {
         ExecuteInstantRedemption::handler(ctx)
     }
 
+    /// Burn coins for their pro-rata share of the coin's bonds, with the matching USDC reserve share
+    pub fn redeem_to_bonds(ctx: Context<RedeemToBonds>, args: RedeemToBondsArgs) -> Result<()> {
+        RedeemToBonds::handler(ctx, args)
+    }
+
+    pub fn configure_in_kind_redemption(
+        ctx: Context<ConfigureInKindRedemption>,
+        args: ConfigureInKindRedemptionArgs,
+    ) -> Result<()> {
+        ConfigureInKindRedemption::handler(ctx, args)
+    }
+
     pub fn initialize_nft_redemption(ctx: Context<InitializeNFTRedemption>) -> Result<()> {
         InitializeNFTRedemption::handler(ctx)
     }
@@ -1,5 +1,10 @@
 use super::*;
 
+/// Restate a raw token amount with `from_decimals` as one with `to_decimals`
+pub fn convert_decimals(amount: u64, from_decimals: u8, to_decimals: u8, rounding: Rounding) -> Result<u64> {
+    FixedPoint::from_amount(amount, from_decimals).to_amount(to_decimals, rounding)
+}
+
 /// Calculate the amount of sovereign coins to mint based on USDC input and exchange rate
 /// Updated to use Switchboard On-Demand
 pub fn calculate_sovereign_coin_amount(
@@ -7,12 +12,13 @@ pub fn calculate_sovereign_coin_amount(
     base_price_feed: &AccountInfo,  // USD/Target feed (e.g., USD/MXN)
     quote_price_feed: Option<&AccountInfo>,  // Optional secondary feed
     target_currency: &str,
-    decimals: u8,  // Decimals of both USDC and sovereign coin
+    payment_decimals: u8,  // Decimals of the payment mint (USDC)
+    coin_decimals: u8,     // Decimals of the sovereign coin
 ) -> Result<u64> {
     // Get the oracle price using On-Demand feeds
     let (price_mantissa, price_scale) = if target_currency == "USD" {
         // For USD sovereign coins, use 1:1 with USDC
-        return Ok(usdc_amount);
+        return convert_decimals(usdc_amount, payment_decimals, coin_decimals, Rounding::Down);
     } else if let Some(quote_feed) = quote_price_feed {
         // For other currencies, calculate cross price
         switchboard::calculate_cross_price_on_demand(base_price_feed, quote_feed)?
@@ -21,34 +27,27 @@ pub fn calculate_sovereign_coin_amount(
         switchboard::get_oracle_price_on_demand(base_price_feed)?
     };
 
-    // Convert to FixedPoint using proper scaling
-    // Since USDC has 6 decimals, we need to scale the amount
-    let usdc_scaled = (usdc_amount as u128)
-        .checked_mul(10u128.pow(decimals as u32))
-        .ok_or(StablecoinError::MathError)?;
-    
-    // Calculate: amount * price
-    let sovereign_scaled = usdc_scaled
-        .checked_mul(price_mantissa.unsigned_abs() as u128)
-        .ok_or(StablecoinError::MathError)?;
-    
-    // Adjust for oracle scale and coin decimals
-    // Result = (usdc * 10^decimals * price_mantissa) / (10^price_scale * 10^decimals)
-    let total_scale = price_scale.checked_add(decimals as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let divisor = 10u128.checked_pow(total_scale as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let sovereign_amount = sovereign_scaled
-        .checked_div(divisor)
-        .ok_or(StablecoinError::MathError)?;
-    
-    if sovereign_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
-    }
-    
-    Ok(sovereign_amount as u64)
+    calculate_sovereign_coin_amount_at_price(usdc_amount, price_mantissa, price_scale, payment_decimals, coin_decimals)
+}
+
+/// Sovereign coins for `usdc_amount` at an already resolved price of target currency per USDC,
+/// rounded down so a minter never receives more than the USDC paid for
+pub fn calculate_sovereign_coin_amount_at_price(
+    usdc_amount: u64,
+    price_mantissa: i128,
+    price_scale: u32,
+    payment_decimals: u8,
+    coin_decimals: u8,
+) -> Result<u64> {
+    let price = FixedPoint::try_from((price_mantissa, price_scale))?;
+    require!(price.is_positive(), StablecoinError::InvalidPriceFeed);
+
+    // Work at the finer of the two scales so nothing is dropped before the final rounding
+    let working_scale = payment_decimals.max(coin_decimals) as u32;
+    FixedPoint::from_amount(usdc_amount, payment_decimals)
+        .rescale(working_scale, Rounding::Down)?
+        .checked_mul(&price, Rounding::Down)?
+        .to_amount(coin_decimals, Rounding::Down)
 }
 
 /// Calculate the USDC amount needed to mint a specific amount of sovereign coins
@@ -59,12 +58,13 @@ pub fn calculate_usdc_for_sovereign_amount(
     base_price_feed: &AccountInfo,
     quote_price_feed: Option<&AccountInfo>,
     target_currency: &str,
-    decimals: u8,
+    payment_decimals: u8,
+    coin_decimals: u8,
 ) -> Result<u64> {
     // Get the oracle price using On-Demand feeds
     let (price_mantissa, price_scale) = if target_currency == "USD" {
         // For USD sovereign coins, use 1:1 with USDC
-        return Ok(sovereign_amount);
+        return convert_decimals(sovereign_amount, coin_decimals, payment_decimals, Rounding::Down);
     } else if let Some(quote_feed) = quote_price_feed {
         // For other currencies, calculate cross price
         switchboard::calculate_cross_price_on_demand(
@@ -74,24 +74,80 @@ pub fn calculate_usdc_for_sovereign_amount(
         switchboard::get_oracle_price_on_demand(base_price_feed)?
     };
 
-    // Scale sovereign amount to match decimals
-    let sovereign_scaled = (sovereign_amount as u128)
-        .checked_mul(10u128.pow(price_scale as u32))
-        .ok_or(StablecoinError::MathError)?;
-    
-    // Calculate: sovereign_amount / price
-    let usdc_scaled = sovereign_scaled
-        .checked_div(price_mantissa.unsigned_abs() as u128)
-        .ok_or(StablecoinError::MathError)?;
-    
-    // Adjust for decimals
-    let usdc_amount = usdc_scaled
-        .checked_div(10u128.pow(decimals as u32))
-        .ok_or(StablecoinError::MathError)?;
-    
-    if usdc_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
+    calculate_usdc_for_sovereign_amount_at_price(sovereign_amount, price_mantissa, price_scale, payment_decimals, coin_decimals)
+}
+
+/// USDC for `sovereign_amount` at an already resolved price of target currency per USDC,
+/// rounded down so a redeemer never takes out more than the coins are worth
+pub fn calculate_usdc_for_sovereign_amount_at_price(
+    sovereign_amount: u64,
+    price_mantissa: i128,
+    price_scale: u32,
+    payment_decimals: u8,
+    coin_decimals: u8,
+) -> Result<u64> {
+    let price = FixedPoint::try_from((price_mantissa, price_scale))?;
+    require!(price.is_positive(), StablecoinError::InvalidPriceFeed);
+
+    let working_scale = payment_decimals.max(coin_decimals) as u32;
+    FixedPoint::from_amount(sovereign_amount, coin_decimals)
+        .rescale(working_scale, Rounding::Down)?
+        .checked_div(&price, Rounding::Down)?
+        .to_amount(payment_decimals, Rounding::Down)
+}
+
+/// USD coin amount bought by `usdc_amount` while USDC is off its peg, at the USDC/USD price
+pub fn calculate_usd_amount_at_usdc_price(
+    usdc_amount: u64,
+    usdc_price: u64,
+    payment_decimals: u8,
+    coin_decimals: u8,
+) -> Result<u64> {
+    let working_scale = payment_decimals.max(coin_decimals) as u32;
+    FixedPoint::from_amount(usdc_amount, payment_decimals)
+        .rescale(working_scale, Rounding::Down)?
+        .checked_mul(&FixedPoint::from_amount(usdc_price, PRICE_PRECISION_DECIMALS), Rounding::Down)?
+        .to_amount(coin_decimals, Rounding::Down)
+}
+
+/// USDC paid for `usd_amount` of a USD coin while USDC is off its peg, at the USDC/USD price
+pub fn calculate_usdc_for_usd_amount_at_price(
+    usd_amount: u64,
+    usdc_price: u64,
+    payment_decimals: u8,
+    coin_decimals: u8,
+) -> Result<u64> {
+    let working_scale = payment_decimals.max(coin_decimals) as u32;
+    FixedPoint::from_amount(usd_amount, coin_decimals)
+        .rescale(working_scale, Rounding::Down)?
+        .checked_div(&FixedPoint::from_amount(usdc_price, PRICE_PRECISION_DECIMALS), Rounding::Down)?
+        .to_amount(payment_decimals, Rounding::Down)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 17.25 MXN per USDC at the oracle scale
+    const MXN_PER_USDC: (i128, u32) = (17_250_000_000_000_000_000, ORACLE_PRICE_SCALE);
+
+    #[test]
+    fn usdc_for_coins_divides_by_the_decimals_once() {
+        // 17.25 MXN coins are worth one USDC, the old conversion divided by 10^6 a second time
+        let usdc = calculate_usdc_for_sovereign_amount_at_price(17_250_000, MXN_PER_USDC.0, MXN_PER_USDC.1, 6, 6).unwrap();
+        assert_eq!(usdc, 1_000_000);
+
+        // Coins with more decimals than USDC convert to the same value
+        let usdc = calculate_usdc_for_sovereign_amount_at_price(17_250_000_000, MXN_PER_USDC.0, MXN_PER_USDC.1, 6, 9).unwrap();
+        assert_eq!(usdc, 1_000_000);
     }
-    
-    Ok(usdc_amount as u64)
-}
\ No newline at end of file
+
+    #[test]
+    fn coin_and_usdc_conversions_round_trip() {
+        let coins = calculate_sovereign_coin_amount_at_price(250_000_000, MXN_PER_USDC.0, MXN_PER_USDC.1, 6, 6).unwrap();
+        assert_eq!(coins, 4_312_500_000);
+
+        let usdc = calculate_usdc_for_sovereign_amount_at_price(coins, MXN_PER_USDC.0, MXN_PER_USDC.1, 6, 6).unwrap();
+        assert_eq!(usdc, 250_000_000);
+    }
+}
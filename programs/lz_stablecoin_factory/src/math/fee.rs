@@ -11,15 +11,10 @@ pub fn calculate_protocol_fee(
         return Ok((amount, 0));
     }
     
-    let fee_amount = (amount as u128)
-        .safe_mul(fee_bps as u128)?
-        .safe_div(BASIS_POINT_MAX as u128)?;
-        
-    if fee_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
-    }
-    
-    let fee = fee_amount as u64;
+    // Fee dust goes to the protocol
+    let fee = FixedPoint::new(amount as i128)
+        .checked_mul(&FixedPoint::from_bps(fee_bps), Rounding::Up)?
+        .to_u64(Rounding::Up)?;
     let net_amount = amount.safe_sub(fee)?;
     
     Ok((net_amount, fee))
@@ -51,14 +46,148 @@ pub fn calculate_gross_amount_with_fee(
         return Ok(net_amount);
     }
     
-    // Formula: gross = net * BASIS_POINT_MAX / (BASIS_POINT_MAX - fee_bps)
-    let numerator = (net_amount as u128).safe_mul(BASIS_POINT_MAX as u128)?;
-    let denominator = (BASIS_POINT_MAX as u128).safe_sub(fee_bps as u128)?;
-    let gross_amount = numerator.safe_div(denominator)?;
-    
-    if gross_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
+    // Formula: gross = net / (1 - fee_bps / BASIS_POINT_MAX), rounded up so the net always arrives
+    let net_share = FixedPoint::from_bps(BASIS_POINT_MAX - fee_bps);
+    FixedPoint::new(net_amount as i128)
+        .checked_div(&net_share, Rounding::Up)?
+        .to_u64(Rounding::Up)
+}
+
+/// Live USDC reserve ratio as a share of the coin's required reserve, 10000 meaning on target.
+/// Saturates at u16::MAX for coins far over target or with no reserve requirement.
+pub fn calculate_reserve_health(
+    usdc_amount: u64,
+    bond_amount: u64,
+    required_reserve_percentage: u16,
+) -> Result<u16> {
+    let total_backing = (usdc_amount as u128).safe_add(bond_amount as u128)?;
+    if total_backing == 0 {
+        // Nothing minted yet, the first mint lands exactly on target
+        return Ok(BASIS_POINT_MAX);
     }
-    
-    Ok(gross_amount as u64)
-}
\ No newline at end of file
+    if required_reserve_percentage == 0 {
+        return Ok(u16::MAX);
+    }
+
+    // Reserve ratio over the required ratio, carried at basis point scale with a single rounding
+    let health = FixedPoint::new(usdc_amount as i128)
+        .rescale(4, Rounding::Down)?
+        .checked_div(&FixedPoint::from_bps(required_reserve_percentage), Rounding::Down)?
+        .checked_div(&FixedPoint::new(total_backing as i128), Rounding::Down)?;
+    Ok(u16::try_from(health.value()).unwrap_or(u16::MAX))
+}
+
+/// Piecewise linear fee at `reserve_health_bps`, flat beyond the first and last breakpoints
+pub fn interpolate_fee_bps(
+    points: &[DynamicFeePoint],
+    reserve_health_bps: u16,
+    fee_of: impl Fn(&DynamicFeePoint) -> u16,
+) -> Result<u16> {
+    let first = points.first().ok_or(StablecoinError::InvalidDynamicFeeCurve)?;
+    if reserve_health_bps <= first.reserve_health_bps {
+        return Ok(fee_of(first));
+    }
+
+    for pair in points.windows(2) {
+        let (lower, upper) = (&pair[0], &pair[1]);
+        if reserve_health_bps > upper.reserve_health_bps {
+            continue;
+        }
+
+        // Signed change across the segment, so rising and falling segments share one formula
+        let lower_fee = FixedPoint::new(fee_of(lower) as i128);
+        let fee_change = FixedPoint::new(fee_of(upper) as i128).checked_sub(&lower_fee)?;
+        let span = FixedPoint::new((upper.reserve_health_bps - lower.reserve_health_bps) as i128);
+        let offset = FixedPoint::new((reserve_health_bps - lower.reserve_health_bps) as i128);
+
+        // Rounded up, fee dust goes to the protocol
+        let fee_bps = fee_change
+            .checked_mul(&offset, Rounding::Up)?
+            .checked_div(&span, Rounding::Up)?
+            .checked_add(&lower_fee)?
+            .to_u64(Rounding::Up)?;
+        return u16::try_from(fee_bps).map_err(|_| error!(StablecoinError::MathError));
+    }
+
+    // Past the last breakpoint
+    Ok(fee_of(&points[points.len() - 1]))
+}
+
+/// Rate a trade actually clears at once the FX spread is taken out of the oracle mid rate.
+/// Minters get fewer coins per USDC, redeemers give up more coins per USDC.
+pub fn calculate_spread_rate(mid_rate: u64, spread_bps: u16, is_mint: bool) -> Result<u64> {
+    require!(spread_bps < BASIS_POINT_MAX, StablecoinError::InvalidFxSpread);
+    let discounted = FixedPoint::from_bps(BASIS_POINT_MAX - spread_bps);
+    let mid_rate = FixedPoint::new(mid_rate as i128);
+
+    if is_mint {
+        mid_rate.checked_mul(&discounted, Rounding::Down)?.to_u64(Rounding::Down)
+    } else {
+        mid_rate.checked_div(&discounted, Rounding::Up)?.to_u64(Rounding::Up)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(reserve_health_bps: u16, mint_fee_bps: u16, redeem_fee_bps: u16) -> DynamicFeePoint {
+        DynamicFeePoint { reserve_health_bps, mint_fee_bps, redeem_fee_bps }
+    }
+
+    // Mint fee rises with health, redeem fee falls
+    fn curve() -> [DynamicFeePoint; 3] {
+        [point(5000, 10, 100), point(10000, 30, 30), point(15000, 60, 10)]
+    }
+
+    #[test]
+    fn fee_is_flat_beyond_the_end_breakpoints() {
+        let points = curve();
+
+        assert_eq!(interpolate_fee_bps(&points, 0, |p| p.mint_fee_bps).unwrap(), 10);
+        assert_eq!(interpolate_fee_bps(&points, 5000, |p| p.redeem_fee_bps).unwrap(), 100);
+        assert_eq!(interpolate_fee_bps(&points, 15000, |p| p.mint_fee_bps).unwrap(), 60);
+        assert_eq!(interpolate_fee_bps(&points, u16::MAX, |p| p.redeem_fee_bps).unwrap(), 10);
+    }
+
+    #[test]
+    fn fee_interpolates_between_breakpoints() {
+        let points = curve();
+
+        assert_eq!(interpolate_fee_bps(&points, 10000, |p| p.mint_fee_bps).unwrap(), 30);
+        assert_eq!(interpolate_fee_bps(&points, 7500, |p| p.mint_fee_bps).unwrap(), 20);
+        assert_eq!(interpolate_fee_bps(&points, 7500, |p| p.redeem_fee_bps).unwrap(), 65);
+        assert_eq!(interpolate_fee_bps(&points, 12500, |p| p.mint_fee_bps).unwrap(), 45);
+        assert_eq!(interpolate_fee_bps(&points, 12500, |p| p.redeem_fee_bps).unwrap(), 20);
+
+        // A fraction of a basis point rounds up
+        assert_eq!(interpolate_fee_bps(&points, 5001, |p| p.mint_fee_bps).unwrap(), 11);
+    }
+
+    #[test]
+    fn fee_needs_at_least_one_breakpoint() {
+        assert!(interpolate_fee_bps(&[], 10000, |p| p.mint_fee_bps).is_err());
+        assert_eq!(interpolate_fee_bps(&[point(8000, 25, 40)], 10000, |p| p.mint_fee_bps).unwrap(), 25);
+    }
+
+    #[test]
+    fn reserve_health_is_the_reserve_ratio_over_the_target() {
+        // 20% reserve against a 20% target
+        assert_eq!(calculate_reserve_health(2_000, 8_000, 2000).unwrap(), 10000);
+        // Half the target
+        assert_eq!(calculate_reserve_health(1_000, 9_000, 2000).unwrap(), 5000);
+        // A third of the backing against a 50% target rounds down
+        assert_eq!(calculate_reserve_health(1, 2, 5000).unwrap(), 6666);
+    }
+
+    #[test]
+    fn reserve_health_edge_cases() {
+        // Nothing minted yet sits on target
+        assert_eq!(calculate_reserve_health(0, 0, 2000).unwrap(), BASIS_POINT_MAX);
+        // No reserve required, or far over it, saturates
+        assert_eq!(calculate_reserve_health(1_000, 9_000, 0).unwrap(), u16::MAX);
+        assert_eq!(calculate_reserve_health(10_000, 0, 100).unwrap(), u16::MAX);
+        // No reserve left at all
+        assert_eq!(calculate_reserve_health(0, 10_000, 2000).unwrap(), 0);
+    }
+}
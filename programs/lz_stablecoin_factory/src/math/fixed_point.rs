@@ -1,170 +1,413 @@
 use super::*;
 
-/// Fixed-point math with precise decimal operations
-#[derive(Clone, Debug)]
+/// Signed fixed-point decimal, `value / 10^scale`.
+/// Every operation that can lose digits takes an explicit `Rounding`, Up rounding toward
+/// positive infinity and Down toward negative infinity, so callers choose who keeps the dust.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FixedPoint {
-    value: u128,
-    scale: u8,
+    value: i128,
+    scale: u32,
 }
 
 impl FixedPoint {
-    /// Create a new fixed-point value
-    pub fn new(value: u128) -> Option<Self> {
-        Some(Self { value, scale: 0 })
+    /// Largest scale whose power of ten still fits in an i128
+    pub const MAX_SCALE: u32 = 38;
+
+    /// Create a whole number
+    pub fn new(value: i128) -> Self {
+        Self { value, scale: 0 }
     }
-    
+
     /// Create with specific scale (10^scale)
-    pub fn new_with_scale(value: u128, scale: u8) -> Option<Self> {
-        Some(Self { value, scale })
+    pub fn new_with_scale(value: i128, scale: u32) -> Result<Self> {
+        require!(scale <= Self::MAX_SCALE, StablecoinError::MathError);
+        Ok(Self { value, scale })
+    }
+
+    /// A raw token amount, its decimals becoming the scale
+    pub fn from_amount(amount: u64, decimals: u8) -> Self {
+        Self { value: amount as i128, scale: decimals as u32 }
     }
-    
+
+    /// Basis points as a fraction, 10000 being one
+    pub fn from_bps(bps: u16) -> Self {
+        Self { value: bps as i128, scale: 4 }
+    }
+
     /// Create fixed-point from integer and decimal parts
     pub fn from_decimal(integer: u64, decimal: u64, decimal_places: u8) -> Result<Self> {
-        // Create from integer part
-        let integer_part = Self {
-            value: integer as u128,
-            scale: 0,
-        };
-        
-        // Calculate decimal scaling factor
-        let scaling_factor = 10u128.checked_pow(decimal_places as u32)
-            .ok_or(StablecoinError::MathError)?;
-            
-        // Create decimal part
-        let decimal_part = Self {
-            value: decimal as u128,
-            scale: decimal_places,
-        };
-        
-        // Add parts together
+        let integer_part = Self::new(integer as i128);
+        let decimal_part = Self::new_with_scale(decimal as i128, decimal_places as u32)?;
+
         integer_part.checked_add(&decimal_part)
     }
-    
-    /// Convert to u64, with error if too large
-    pub fn to_u64(&self) -> Result<u64> {
-        if self.scale == 0 {
-            // No decimal part, just check if it fits in u64
-            if self.value > u64::MAX as u128 {
-                return Err(StablecoinError::MathError.into());
-            }
-            return Ok(self.value as u64);
-        }
-        
-        // With decimal part, need to divide by 10^scale
-        let divisor = 10u128.checked_pow(self.scale as u32)
-            .ok_or(StablecoinError::MathError)?;
-            
-        let result = self.value
-            .checked_div(divisor)
-            .ok_or(StablecoinError::MathError)?;
-            
-        if result > u64::MAX as u128 {
-            return Err(StablecoinError::MathError.into());
-        }
-        
-        Ok(result as u64)
+
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.value > 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.value < 0
+    }
+
+    /// Restate at another scale, rounding when digits are dropped
+    pub fn rescale(&self, scale: u32, rounding: Rounding) -> Result<Self> {
+        require!(scale <= Self::MAX_SCALE, StablecoinError::MathError);
+
+        let value = if scale >= self.scale {
+            self.value.checked_mul(pow10(scale - self.scale)?)
+                .ok_or(StablecoinError::MathError)?
+        } else {
+            div_rounding(self.value, pow10(self.scale - scale)?, rounding)?
+        };
+
+        Ok(Self { value, scale })
+    }
+
+    /// Raw token amount with `decimals`, rejecting negatives and anything beyond u64
+    pub fn to_amount(&self, decimals: u8, rounding: Rounding) -> Result<u64> {
+        let amount = self.rescale(decimals as u32, rounding)?.value;
+        u64::try_from(amount).map_err(|_| error!(StablecoinError::MathError))
+    }
+
+    /// Convert to u64 whole units
+    pub fn to_u64(&self, rounding: Rounding) -> Result<u64> {
+        self.to_amount(0, rounding)
     }
-    
+
     /// Add two fixed-point values
     pub fn checked_add(&self, other: &Self) -> Result<Self> {
-        // Adjust scales to match
         let (left, right) = self.align_scales(other)?;
-        
-        let result_value = left.value.safe_add(right.value)?;
-        
+
         Ok(Self {
-            value: result_value,
+            value: left.value.checked_add(right.value).ok_or(StablecoinError::MathError)?,
             scale: left.scale,
         })
     }
-    
+
     /// Subtract one fixed-point value from another
     pub fn checked_sub(&self, other: &Self) -> Result<Self> {
-        // Adjust scales to match
         let (left, right) = self.align_scales(other)?;
-        
-        let result_value = left.value.safe_sub(right.value)?;
-        
+
         Ok(Self {
-            value: result_value,
+            value: left.value.checked_sub(right.value).ok_or(StablecoinError::MathError)?,
             scale: left.scale,
         })
     }
-    
-    /// Multiply two fixed-point values
-    pub fn checked_mul(&self, other: &Self) -> Result<Self> {
-        let result_value = self.value.safe_mul(other.value)?;
-        let result_scale = self.scale.checked_add(other.scale)
-            .ok_or(StablecoinError::MathError)?;
-            
+
+    /// Multiply two fixed-point values, keeping this value's scale.
+    /// The product is formed in 256 bits, only a result beyond i128 overflows.
+    pub fn checked_mul(&self, other: &Self, rounding: Rounding) -> Result<Self> {
         Ok(Self {
-            value: result_value,
-            scale: result_scale,
+            value: mul_div_rounding(self.value, other.value, pow10(other.scale)?, rounding)?,
+            scale: self.scale,
         })
     }
-    
-    /// Divide one fixed-point value by another
-    pub fn checked_div(&self, other: &Self) -> Result<Self> {
+
+    /// Divide one fixed-point value by another, keeping this value's scale.
+    /// The scaled numerator is formed in 256 bits, only a result beyond i128 overflows.
+    pub fn checked_div(&self, other: &Self, rounding: Rounding) -> Result<Self> {
         require!(other.value != 0, StablecoinError::DivisionByZero);
-        
-        // For better precision, multiply numerator by 10^6 before division
-        const EXTRA_PRECISION: u32 = 6;
-        
-        let scaled_value = self.value
-            .checked_mul(10u128.pow(EXTRA_PRECISION))
-            .ok_or(StablecoinError::MathError)?;
-            
-        let result_value = scaled_value
-            .checked_div(other.value)
-            .ok_or(StablecoinError::MathError)?;
-            
-        // Calculate new scale: self.scale - other.scale + EXTRA_PRECISION
-        let result_scale = self.scale
-            .checked_add(EXTRA_PRECISION as u8)
-            .ok_or(StablecoinError::MathError)?
-            .checked_sub(other.scale)
-            .unwrap_or_else(|| EXTRA_PRECISION as u8); // If other.scale > self.scale + EXTRA_PRECISION
-            
+
         Ok(Self {
-            value: result_value,
-            scale: result_scale,
+            value: mul_div_rounding(self.value, pow10(other.scale)?, other.value, rounding)?,
+            scale: self.scale,
         })
     }
-    
+
+    /// Raise to a whole power, rounding each intermediate product at this value's scale
+    pub fn checked_pow(&self, exponent: u32, rounding: Rounding) -> Result<Self> {
+        let mut result = Self { value: pow10(self.scale)?, scale: self.scale };
+        for _ in 0..exponent {
+            result = result.checked_mul(self, rounding)?;
+        }
+
+        Ok(result)
+    }
+
+    /// e raised to this value, at this value's scale. Whole powers of e times a Taylor series
+    /// for the remaining fraction, every step rounded the same way so the result stays on one side.
+    pub fn checked_exp(&self, rounding: Rounding) -> Result<Self> {
+        let one = Self { value: pow10(self.scale)?, scale: self.scale };
+        let whole = self.rescale(0, Rounding::Down)?;
+        let fraction = self.checked_sub(&whole)?;
+
+        // The fraction is in [0, 1) so each term is less than half the one before
+        let mut fraction_exp = one;
+        let mut term = one;
+        for n in 1..=EXP_SERIES_TERMS {
+            term = term
+                .checked_mul(&fraction, rounding)?
+                .checked_div(&Self::new(n as i128), rounding)?;
+            if term.value == 0 {
+                break;
+            }
+            fraction_exp = fraction_exp.checked_add(&term)?;
+            if term.value == 1 && rounding == Rounding::Up {
+                // Rounded up terms never reach zero, one more unit covers the rest of the series
+                fraction_exp = fraction_exp.checked_add(&Self { value: 1, scale: self.scale })?;
+                break;
+            }
+        }
+
+        let whole_power = u32::try_from(whole.value.unsigned_abs()).map_err(|_| error!(StablecoinError::MathError))?;
+        let whole_exp = if whole.is_negative() {
+            // Dividing by e^n flips the direction its own rounding pushes the result
+            let opposite = if rounding == Rounding::Up { Rounding::Down } else { Rounding::Up };
+            Self::new(1)
+                .rescale(18, rounding)?
+                .checked_div(&euler(opposite)?.checked_pow(whole_power, opposite)?, rounding)?
+        } else {
+            euler(rounding)?.checked_pow(whole_power, rounding)?
+        };
+
+        whole_exp
+            .rescale(self.scale, rounding)?
+            .checked_mul(&fraction_exp, rounding)
+    }
+
     /// Align two fixed-point values to have the same scale
     fn align_scales(&self, other: &Self) -> Result<(Self, Self)> {
-        if self.scale == other.scale {
-            return Ok((self.clone(), other.clone()));
+        let scale = self.scale.max(other.scale);
+
+        Ok((
+            self.rescale(scale, Rounding::Down)?,
+            other.rescale(scale, Rounding::Down)?,
+        ))
+    }
+}
+
+/// Oracle prices arrive as a mantissa and a power of ten scale
+impl TryFrom<(i128, u32)> for FixedPoint {
+    type Error = anchor_lang::error::Error;
+
+    fn try_from((mantissa, scale): (i128, u32)) -> Result<Self> {
+        Self::new_with_scale(mantissa, scale)
+    }
+}
+
+impl From<FixedPoint> for (i128, u32) {
+    fn from(fixed_point: FixedPoint) -> Self {
+        (fixed_point.value, fixed_point.scale)
+    }
+}
+
+/// Terms of the exp series, enough for a fraction below one to vanish at MAX_SCALE
+const EXP_SERIES_TERMS: u32 = 40;
+
+/// e at 18 decimals, rounded in the requested direction
+fn euler(rounding: Rounding) -> Result<FixedPoint> {
+    let value = match rounding {
+        Rounding::Down => 2_718_281_828_459_045_235,
+        Rounding::Up => 2_718_281_828_459_045_236,
+    };
+    FixedPoint::new_with_scale(value, 18)
+}
+
+fn pow10(exponent: u32) -> Result<i128> {
+    10i128.checked_pow(exponent).ok_or_else(|| error!(StablecoinError::MathError))
+}
+
+/// `a * b / denominator` with a 256-bit intermediate, rounded toward positive (Up) or negative (Down) infinity
+fn mul_div_rounding(a: i128, b: i128, denominator: i128, rounding: Rounding) -> Result<i128> {
+    require!(denominator != 0, StablecoinError::DivisionByZero);
+
+    let is_negative = (a < 0) ^ (b < 0) ^ (denominator < 0);
+    let (quotient, inexact) = wide_mul_div(a.unsigned_abs(), b.unsigned_abs(), denominator.unsigned_abs())
+        .ok_or(StablecoinError::MathError)?;
+
+    // The magnitude was truncated toward zero, step away from it when that is the rounding direction
+    let magnitude = match rounding {
+        Rounding::Up if inexact && !is_negative => quotient.checked_add(1),
+        Rounding::Down if inexact && is_negative => quotient.checked_add(1),
+        _ => Some(quotient),
+    }
+    .ok_or(StablecoinError::MathError)?;
+
+    let value = i128::try_from(magnitude).map_err(|_| error!(StablecoinError::MathError))?;
+    Ok(if is_negative { -value } else { value })
+}
+
+/// floor(a * b / denominator) and whether anything was left over, None when the quotient needs more than 128 bits
+fn wide_mul_div(a: u128, b: u128, denominator: u128) -> Option<(u128, bool)> {
+    let (high, low) = wide_mul(a, b);
+    if high == 0 {
+        return Some((low / denominator, low % denominator != 0));
+    }
+    if high >= denominator {
+        return None;
+    }
+
+    // Schoolbook binary long division of the low half, the high half being the starting remainder
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
         }
-        
-        if self.scale < other.scale {
-            // Scale up self to match other
-            let scale_diff = other.scale - self.scale;
-            let scaling_factor = 10u128.checked_pow(scale_diff as u32)
-                .ok_or(StablecoinError::MathError)?;
-                
-            let scaled_value = self.value
-                .checked_mul(scaling_factor)
-                .ok_or(StablecoinError::MathError)?;
-                
-            Ok((
-                Self { value: scaled_value, scale: other.scale },
-                other.clone()
-            ))
-        } else {
-            // Scale up other to match self
-            let scale_diff = self.scale - other.scale;
-            let scaling_factor = 10u128.checked_pow(scale_diff as u32)
-                .ok_or(StablecoinError::MathError)?;
-                
-            let scaled_value = other.value
-                .checked_mul(scaling_factor)
-                .ok_or(StablecoinError::MathError)?;
-                
-            Ok((
-                self.clone(),
-                Self { value: scaled_value, scale: self.scale }
-            ))
+    }
+
+    Some((quotient, remainder != 0))
+}
+
+/// Full 256-bit product as (high, low) halves
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const LOW_MASK: u128 = u64::MAX as u128;
+
+    let (a_high, a_low) = (a >> 64, a & LOW_MASK);
+    let (b_high, b_low) = (b >> 64, b & LOW_MASK);
+
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (high_low & LOW_MASK) + (low_high & LOW_MASK);
+    let low = (middle << 64) | (low_low & LOW_MASK);
+    let high = high_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+
+    (high, low)
+}
+
+/// Signed division that rounds toward positive (Up) or negative (Down) infinity
+fn div_rounding(numerator: i128, denominator: i128, rounding: Rounding) -> Result<i128> {
+    require!(denominator != 0, StablecoinError::DivisionByZero);
+
+    let quotient = numerator.checked_div(denominator).ok_or(StablecoinError::MathError)?;
+    let remainder = numerator.checked_rem(denominator).ok_or(StablecoinError::MathError)?;
+    if remainder == 0 {
+        return Ok(quotient);
+    }
+
+    // i128 division truncates toward zero, step away from it when the exact result lies beyond
+    let is_positive = (numerator < 0) == (denominator < 0);
+    let rounded = match rounding {
+        Rounding::Up if is_positive => quotient + 1,
+        Rounding::Down if !is_positive => quotient - 1,
+        _ => quotient,
+    };
+
+    Ok(rounded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fp(value: i128, scale: u32) -> FixedPoint {
+        FixedPoint::new_with_scale(value, scale).unwrap()
+    }
+
+    #[test]
+    fn signed_add_and_sub_align_scales() {
+        // 1.5 + -2.25 = -0.75
+        assert_eq!(fp(15, 1).checked_add(&fp(-225, 2)).unwrap(), fp(-75, 2));
+        // -0.75 - 1.5 = -2.25
+        assert_eq!(fp(-75, 2).checked_sub(&fp(15, 1)).unwrap(), fp(-225, 2));
+        assert!(fp(-1, 0).is_negative() && !fp(-1, 0).is_positive());
+    }
+
+    #[test]
+    fn rescale_rounds_toward_infinities() {
+        assert_eq!(fp(15, 1).rescale(0, Rounding::Up).unwrap(), fp(2, 0));
+        assert_eq!(fp(15, 1).rescale(0, Rounding::Down).unwrap(), fp(1, 0));
+        assert_eq!(fp(-15, 1).rescale(0, Rounding::Up).unwrap(), fp(-1, 0));
+        assert_eq!(fp(-15, 1).rescale(0, Rounding::Down).unwrap(), fp(-2, 0));
+        // Exact values are untouched by either mode
+        assert_eq!(fp(20, 1).rescale(0, Rounding::Up).unwrap(), fp(2, 0));
+        assert_eq!(fp(-20, 1).rescale(0, Rounding::Down).unwrap(), fp(-2, 0));
+    }
+
+    #[test]
+    fn mul_rounds_each_mode_and_sign() {
+        let half = FixedPoint::from_bps(5000);
+        assert_eq!(FixedPoint::new(7).checked_mul(&half, Rounding::Up).unwrap(), fp(4, 0));
+        assert_eq!(FixedPoint::new(7).checked_mul(&half, Rounding::Down).unwrap(), fp(3, 0));
+        assert_eq!(FixedPoint::new(-7).checked_mul(&half, Rounding::Up).unwrap(), fp(-3, 0));
+        assert_eq!(FixedPoint::new(-7).checked_mul(&half, Rounding::Down).unwrap(), fp(-4, 0));
+    }
+
+    #[test]
+    fn div_rounds_each_mode_and_sign() {
+        let three = FixedPoint::new(3);
+        assert_eq!(FixedPoint::new(10).checked_div(&three, Rounding::Up).unwrap(), fp(4, 0));
+        assert_eq!(FixedPoint::new(10).checked_div(&three, Rounding::Down).unwrap(), fp(3, 0));
+        assert_eq!(FixedPoint::new(-10).checked_div(&three, Rounding::Up).unwrap(), fp(-3, 0));
+        assert_eq!(FixedPoint::new(-10).checked_div(&three, Rounding::Down).unwrap(), fp(-4, 0));
+        assert_eq!(FixedPoint::new(10).checked_div(&FixedPoint::new(-3), Rounding::Down).unwrap(), fp(-4, 0));
+        assert!(FixedPoint::new(10).checked_div(&FixedPoint::new(0), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn mul_and_div_widen_past_i128() {
+        // i128::MAX * 10^18 needs more than 128 bits before the division brings it back
+        let one = fp(1_000_000_000_000_000_000, 18);
+        assert_eq!(FixedPoint::new(i128::MAX).checked_mul(&one, Rounding::Down).unwrap(), FixedPoint::new(i128::MAX));
+        assert_eq!(FixedPoint::new(i128::MAX).checked_div(&one, Rounding::Down).unwrap(), FixedPoint::new(i128::MAX));
+        assert_eq!(FixedPoint::new(i128::MIN + 1).checked_mul(&one, Rounding::Up).unwrap(), FixedPoint::new(i128::MIN + 1));
+        // Only a result beyond i128 overflows
+        assert!(FixedPoint::new(i128::MAX).checked_mul(&FixedPoint::new(2), Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn checked_pow_rounds_each_step() {
+        // 1.10^2 = 1.21 exactly
+        assert_eq!(fp(110, 2).checked_pow(2, Rounding::Down).unwrap(), fp(121, 2));
+        // 1.1^2 = 1.21 does not fit one decimal
+        assert_eq!(fp(11, 1).checked_pow(2, Rounding::Up).unwrap(), fp(13, 1));
+        assert_eq!(fp(11, 1).checked_pow(2, Rounding::Down).unwrap(), fp(12, 1));
+        // Anything to the power zero is one at its own scale
+        assert_eq!(fp(123, 2).checked_pow(0, Rounding::Down).unwrap(), fp(100, 2));
+        assert_eq!(FixedPoint::new(-2).checked_pow(3, Rounding::Down).unwrap(), FixedPoint::new(-8));
+        assert!(FixedPoint::new(10).checked_pow(39, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn checked_exp_brackets_the_true_value() {
+        let scale = 18;
+        assert_eq!(fp(0, scale).checked_exp(Rounding::Down).unwrap(), fp(1_000_000_000_000_000_000, scale));
+
+        // e^1 = 2.718281828459045235360..., e^-1 = 0.367879441171442321595..., e^0.05 = 1.051271096376024039697...
+        for (exponent, expected) in [
+            (1_000_000_000_000_000_000, 2_718_281_828_459_045_235),
+            (-1_000_000_000_000_000_000, 367_879_441_171_442_321),
+            (50_000_000_000_000_000, 1_051_271_096_376_024_039),
+        ] {
+            let down = fp(exponent, scale).checked_exp(Rounding::Down).unwrap().value();
+            let up = fp(exponent, scale).checked_exp(Rounding::Up).unwrap().value();
+            assert!(down <= expected && expected < up, "exp({exponent}) not bracketed: {down} {up}");
+            assert!(up - down < 100, "exp({exponent}) bounds too wide: {down} {up}");
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_from_mantissa_and_scale_edges() {
+        assert_eq!(FixedPoint::try_from((i128::MAX, FixedPoint::MAX_SCALE)).unwrap(), fp(i128::MAX, 38));
+        assert_eq!(FixedPoint::try_from((i128::MIN, 0)).unwrap(), fp(i128::MIN, 0));
+        assert_eq!(FixedPoint::try_from((0, 0)).unwrap(), FixedPoint::new(0));
+        assert!(FixedPoint::try_from((1, FixedPoint::MAX_SCALE + 1)).is_err());
+        assert!(FixedPoint::try_from((1, u32::MAX)).is_err());
+
+        let round_trip: (i128, u32) = FixedPoint::try_from((-5, 1)).unwrap().into();
+        assert_eq!(round_trip, (-5, 1));
+    }
+
+    #[test]
+    fn to_amount_rejects_negatives_and_overflow() {
+        assert!(fp(-1, 0).to_amount(6, Rounding::Down).is_err());
+        assert!(FixedPoint::new(u64::MAX as i128 + 1).to_u64(Rounding::Down).is_err());
+        assert_eq!(FixedPoint::from_amount(1_234_567, 6).to_amount(2, Rounding::Up).unwrap(), 124);
+    }
+}
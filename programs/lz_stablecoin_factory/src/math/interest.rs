@@ -1,79 +1,79 @@
 use super::*;
 
-/// Calculate Etherfuse fee based on bond yield using integer math
-pub fn calculate_etherfuse_fee(yield_basis_points: u16) -> Result<u16> {
-    // Fee calculation is based on yield percentage
-    // Fixed point calculation with 4 decimal places of precision
-    // This means 1.0000 = 10000
-    
-    // Convert all percentages to our fixed point representation
-    // 4.5% = 450 basis points = 45000 in our fixed point
-    const YIELD_THRESHOLD_1: u32 = 450 * 100; // 4.5% threshold
-    const YIELD_THRESHOLD_2: u32 = 1000 * 100; // 10% threshold
-    
-    // Convert fee percentages to fixed point
-    const FEE_FIXED_1: u32 = 25 * 100;      // 0.25% = 2500 in fixed point
-    const FEE_FIXED_2: u32 = 150 * 100;     // 1.5% = 15000 in fixed point
-    
-    // Formula coefficients in fixed point
-    // 0.2273 = 2273 in fixed point
-    const SLOPE: u32 = 2273;
-    // 0.7727 = 7727 in fixed point
-    const INTERCEPT: u32 = 7727;
-    
-    // Convert basis points to our fixed point representation
-    let yield_fixed = (yield_basis_points as u32) * 100;
-    
-    // Calculate fee using Etherfuse's formula with integer math
-    let fee_fixed = if yield_fixed < YIELD_THRESHOLD_1 {
-        FEE_FIXED_1
-    } else if yield_fixed >= YIELD_THRESHOLD_2 {
-        FEE_FIXED_2
+/// Calculate Etherfuse fee based on bond yield
+pub fn calculate_etherfuse_fee(yield_basis_points: u16, curve: &EtherfuseFeeCurve) -> Result<u16> {
+    let fee_bps = if yield_basis_points < curve.low_yield_threshold_bps {
+        curve.low_fee_bps as u64
+    } else if yield_basis_points >= curve.high_yield_threshold_bps {
+        curve.high_fee_bps as u64
     } else {
-        // Formula: fee = 0.2273 * yield - 0.7727
-        // In fixed point: fee_fixed = (SLOPE * yield_fixed / 10000) - INTERCEPT
-        // To avoid precision loss, we do multiplication first
-        let fee_pre = yield_fixed.checked_mul(SLOPE).ok_or(error!(StablecoinError::MathOverflow))?;
-        let fee_scaled = fee_pre.checked_div(10000).ok_or(error!(StablecoinError::MathOverflow))?;
-        fee_scaled.checked_sub(INTERCEPT).ok_or(error!(StablecoinError::MathOverflow))?
+        // fee = slope * yield - intercept, with the slope and intercept in 4 decimal fixed point
+        // of the yield in percent, so the intercept is restated at 2 decimals of a basis point
+        let slope = FixedPoint::new_with_scale(curve.slope as i128, 4)?;
+        let intercept = FixedPoint::new_with_scale(curve.intercept as i128, 2)?;
+
+        // A negative fee means the curve is misconfigured and fails the conversion to an amount
+        FixedPoint::new(yield_basis_points as i128)
+            .rescale(2, Rounding::Down)?
+            .checked_mul(&slope, Rounding::Down)?
+            .checked_sub(&intercept)?
+            .to_u64(Rounding::Down)?
     };
-    
-    // Convert from fixed point back to basis points
-    // Division by 100 to go from fixed point to basis points
-    let fee_bps = fee_fixed.checked_div(100).ok_or(error!(StablecoinError::MathOverflow))? as u16;
-    
-    Ok(fee_bps)
+
+    u16::try_from(fee_bps).map_err(|_| error!(StablecoinError::MathOverflow))
 }
 
 /// Calculate interest rate for sovereign coin from bond interest rate
 pub fn calculate_sovereign_interest_rate(
-    bond_rate: i16,      // Bond interest rate in basis points
-    bond_amount: u64,    // Amount of bonds held
+    bond_rate: i16,             // Bond interest rate in basis points
+    bond_amount: u64,           // Amount of bonds held
+    curve: &EtherfuseFeeCurve,  // Etherfuse fee terms
+    tax_withholding_bps: u16,   // Withholding for the coin's currency
 ) -> Result<i16> {
-    // Constant tax withholding in basis points (0.5% = 50 basis points)
-    const TAX_WITHHOLDING_BPS: u16 = 50;
-    
     // Bond rate must be positive
     if bond_rate <= 0 {
         return Ok(0);
     }
-    
-    // Convert to u16 for calculation
-    let bond_rate_bps = bond_rate as u16;
-    
-    // Calculate Etherfuse fee in basis points
-    let fee_bps = calculate_etherfuse_fee(bond_rate_bps)?;
-    
-    // Calculate net rate: bond_rate - fee - tax
-    // Using safe math to prevent underflow
-    let net_rate_bps = bond_rate_bps
-        .checked_sub(fee_bps)
-        .ok_or(error!(StablecoinError::MathOverflow))?
-        .checked_sub(TAX_WITHHOLDING_BPS)
-        .ok_or(error!(StablecoinError::MathOverflow))?;
-    
-    // Convert back to i16
-    Ok(net_rate_bps as i16)
+
+    let fee_bps = calculate_etherfuse_fee(bond_rate as u16, curve)?;
+
+    // Net rate: bond_rate - fee - tax, a bond that does not cover its fee and tax pays nothing
+    let net_rate = FixedPoint::new(bond_rate as i128)
+        .checked_sub(&FixedPoint::new(fee_bps as i128))?
+        .checked_sub(&FixedPoint::new(tax_withholding_bps as i128))?;
+    if !net_rate.is_positive() {
+        return Ok(0);
+    }
+
+    i16::try_from(net_rate.value()).map_err(|_| error!(StablecoinError::MathOverflow))
+}
+
+/// Scale the net bond rate down to the holders' share of the yield, the rest is accrued
+/// as protocol and issuer claims instead of compounding into the token
+pub fn apply_holder_yield_share(net_rate: i16, yield_share_holders: u16) -> Result<i16> {
+    if net_rate <= 0 {
+        return Ok(net_rate);
+    }
+
+    let holder_rate = FixedPoint::new(net_rate as i128)
+        .checked_mul(&FixedPoint::from_bps(yield_share_holders), Rounding::Down)?;
+
+    i16::try_from(holder_rate.value()).map_err(|_| error!(StablecoinError::MathOverflow))
+}
+
+/// Simple interest earned on `principal` at `rate_bps` over `elapsed_seconds`, rounded down
+pub fn calculate_accrued_yield(principal: u64, rate_bps: i16, elapsed_seconds: i64) -> Result<u64> {
+    if rate_bps <= 0 || elapsed_seconds <= 0 || principal == 0 {
+        return Ok(0);
+    }
+
+    // Carried at basis point scale so the rate applies exactly and only the year division rounds
+    FixedPoint::new(principal as i128)
+        .checked_mul(&FixedPoint::new(elapsed_seconds as i128), Rounding::Down)?
+        .rescale(4, Rounding::Down)?
+        .checked_mul(&FixedPoint::from_bps(rate_bps as u16), Rounding::Down)?
+        .checked_div(&FixedPoint::new(SECONDS_PER_YEAR as i128), Rounding::Down)?
+        .to_u64(Rounding::Down)
 }
 
 /// Calculate overall interest rate from multiple bonds
@@ -82,58 +82,63 @@ pub fn calculate_weighted_interest_rate(
     bond_rates: [i16; MAX_BOND_MAPPINGS],  // Interest rates in basis points
     bond_amounts: [u64; MAX_BOND_MAPPINGS], // Bond amounts
     bond_count: usize,                     // Number of active bonds
+    curve: &EtherfuseFeeCurve,             // Etherfuse fee terms
+    tax_withholding_bps: u16,              // Withholding for the coin's currency
 ) -> Result<i16> {
-    let mut weighted_sum: u128 = 0;
-    let mut total_amount: u128 = 0;
-    
-    // Calculate weighted sum
+    let mut weighted_sum = FixedPoint::new(0);
+    let mut total_amount = FixedPoint::new(0);
+
     for i in 0..bond_count {
         // Skip negative rates (invalid) and zero amounts
         if bond_rates[i] <= 0 || bond_amounts[i] == 0 {
             continue;
         }
-        
+
         // Calculate net rate after fees and tax
-        let net_rate = calculate_sovereign_interest_rate(bond_rates[i], bond_amounts[i])?;
-        
+        let net_rate = calculate_sovereign_interest_rate(bond_rates[i], bond_amounts[i], curve, tax_withholding_bps)?;
+
         // Skip if net rate is zero or negative
         if net_rate <= 0 {
             continue;
         }
-        
-        // Convert to u128 for safe multiplication
-        let rate_u128 = net_rate as u128;
-        let amount_u128 = bond_amounts[i] as u128;
-        
+
         // Add to weighted sum: rate * amount
-        let product = rate_u128
-            .checked_mul(amount_u128)
-            .ok_or(error!(StablecoinError::MathOverflow))?;
-        
-        weighted_sum = weighted_sum
-            .checked_add(product)
-            .ok_or(error!(StablecoinError::MathOverflow))?;
-        
-        // Add to total amount
-        total_amount = total_amount
-            .checked_add(amount_u128)
-            .ok_or(error!(StablecoinError::MathOverflow))?;
+        let amount = FixedPoint::new(bond_amounts[i] as i128);
+        weighted_sum = weighted_sum.checked_add(
+            &FixedPoint::new(net_rate as i128).checked_mul(&amount, Rounding::Down)?
+        )?;
+        total_amount = total_amount.checked_add(&amount)?;
     }
-    
-    // Calculate weighted average
-    if total_amount == 0 {
+
+    if !total_amount.is_positive() {
         return Ok(0);
     }
-    
-    // Safe division for weighted average
-    let result = weighted_sum
-        .checked_div(total_amount)
-        .ok_or(error!(StablecoinError::MathOverflow))?;
-    
-    // Ensure the result fits in i16
-    if result > i16::MAX as u128 {
-        return Ok(i16::MAX);
-    }
-    
-    Ok(result as i16)
-}
\ No newline at end of file
+
+    // Weighted average, saturating at the largest rate an i16 can carry
+    let result = weighted_sum.checked_div(&total_amount, Rounding::Down)?;
+    Ok(i16::try_from(result.value()).unwrap_or(i16::MAX))
+}
+
+/// Move the current rate towards the target by at most `max_step_bps`,
+/// then keep the result within the configured floor and ceiling
+pub fn smooth_interest_rate(
+    current_rate: i16,
+    target_rate: i16,
+    max_step_bps: u16,
+    rate_floor: i16,
+    rate_ceiling: i16,
+) -> Result<i16> {
+    require!(rate_floor <= rate_ceiling, StablecoinError::InvalidInterestRateBounds);
+
+    let current = current_rate as i32;
+    let max_step = max_step_bps as i32;
+    let step = (target_rate as i32)
+        .safe_sub(current)?
+        .clamp(-max_step, max_step);
+
+    let smoothed = current
+        .safe_add(step)?
+        .clamp(rate_floor as i32, rate_ceiling as i32);
+
+    Ok(smoothed as i16)
+}
@@ -10,6 +10,10 @@ pub mod token_extension;
 pub mod conversion;
 pub mod interest;
 pub mod switchboard;
+pub mod stablebond;
+pub mod valuation;
+pub mod quote;
+pub mod rfq;
 
 
 pub use fixed_point::*;
@@ -21,4 +25,7 @@ pub use reserve::*;
 pub use token_extension::*;
 pub use conversion::*;
 pub use interest::*;
-pub use switchboard::*;
\ No newline at end of file
+pub use switchboard::*;
+pub use valuation::*;
+pub use quote::*;
+pub use rfq::*;
\ No newline at end of file
@@ -0,0 +1,189 @@
+use super::*;
+
+/// How a mint of `usdc_amount` settles, shared by initialize_mint and preview_mint
+pub struct MintQuote {
+    pub net_amount: u64,               // USDC left once the protocol fee is taken
+    pub protocol_fee: u64,
+    pub spread_amount: u64,
+    pub reserve_amount: u64,
+    pub bond_amount: u64,
+    pub accrued_sovereign_amount: u64, // Coins bought, as an accrued UI amount for interest-bearing coins
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+}
+
+/// How a redemption of an accrued coin amount settles, shared by initialize_redeem and preview_redeem
+pub struct RedeemQuote {
+    pub usdc_amount: u64,              // Value of the coins once the spread and any collateral haircut are withheld
+    pub spread_amount: u64,
+    pub collateral_haircut: u64,       // Left behind for the remaining holders while the coin is undercollateralized
+    pub net_amount: u64,               // Paid out once the protocol fee is taken
+    pub protocol_fee: u64,
+    pub from_usdc_reserve: u64,
+    pub from_protocol_vault: u64,
+    pub from_bond_redemption: u64,
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+}
+
+impl RedeemQuote {
+    pub fn redemption_type(&self) -> RedemptionTypeState {
+        if self.from_bond_redemption == 0 {
+            if self.from_protocol_vault == 0 {
+                RedemptionTypeState::UsdcReserveOnly
+            } else {
+                RedemptionTypeState::UsdcReserveAndProtocol
+            }
+        } else {
+            // We need bond redemption - default to instant, NFT will be determined in ExecuteNFTRedemption
+            RedemptionTypeState::InstantBondRedemption
+        }
+    }
+}
+
+/// Mid rate of the trade scaled by PRICE_PRECISION: the USDC/USD price for a depegged USD coin,
+/// the payment price otherwise, one for USD coins at par
+pub fn calculate_oracle_rate(payment_price: Option<(i128, u32)>, usdc_depeg_price: Option<u64>) -> Result<u64> {
+    match (usdc_depeg_price, payment_price) {
+        (Some(usdc_price), _) => Ok(usdc_price),
+        (None, Some((price_mantissa, price_scale))) => switchboard::to_price_precision(price_mantissa, price_scale),
+        (None, None) => Ok(PRICE_PRECISION),
+    }
+}
+
+pub fn quote_mint(
+    sovereign_coin: &SovereignCoin,
+    usdc_amount: u64,
+    mint_fee_bps: u16,
+    required_reserve_percentage: u16,
+    payment_price: Option<(i128, u32)>,
+    usdc_depeg_price: Option<u64>,
+) -> Result<MintQuote> {
+    let (net_amount, protocol_fee) = fee::calculate_protocol_fee(usdc_amount, mint_fee_bps)?;
+
+    // The spread's share of the USDC goes to the spread recipient, only the rest backs the coin
+    let spread_bps = sovereign_coin.mint_spread_bps;
+    let spread_amount = calculate_percentage(net_amount, spread_bps, Rounding::Up)?;
+
+    let (reserve_amount, bond_amount) = reserve::calculate_reserve_and_bond_amounts(
+        net_amount.safe_sub(spread_amount)?,
+        required_reserve_percentage,
+    )?;
+
+    let mid_sovereign_amount = match (usdc_depeg_price, payment_price) {
+        (Some(usdc_price), _) => conversion::calculate_usd_amount_at_usdc_price(
+            usdc_amount,
+            usdc_price,
+            USDC_DECIMALS,
+            sovereign_coin.decimals,
+        )?,
+        (None, Some((price_mantissa, price_scale))) => conversion::calculate_sovereign_coin_amount_at_price(
+            usdc_amount,
+            price_mantissa,
+            price_scale,
+            USDC_DECIMALS,
+            sovereign_coin.decimals,
+        )?,
+        (None, None) => conversion::convert_decimals(
+            usdc_amount,
+            USDC_DECIMALS,
+            sovereign_coin.decimals,
+            Rounding::Down,
+        )?,
+    };
+    let accrued_sovereign_amount = mul_div(
+        mid_sovereign_amount,
+        (BASIS_POINT_MAX - spread_bps) as u64,
+        BASIS_POINT_MAX as u64,
+        Rounding::Down,
+    )?;
+
+    let oracle_rate = calculate_oracle_rate(payment_price, usdc_depeg_price)?;
+
+    Ok(MintQuote {
+        net_amount,
+        protocol_fee,
+        spread_amount,
+        reserve_amount,
+        bond_amount,
+        accrued_sovereign_amount,
+        oracle_rate,
+        applied_rate: fee::calculate_spread_rate(oracle_rate, spread_bps, true)?,
+    })
+}
+
+/// `user_share_of_fiat_reserve` caps what the coin's own reserve pays, the protocol vault
+/// covers what it can of the rest and bonds are redeemed for whatever remains. Basket coins skip
+/// the protocol vault, it can only take a single bond in exchange.
+/// Below full collateral the payout is scaled by `collateral_ratio_bps` so the coin winds down pro rata.
+pub fn quote_redeem(
+    sovereign_coin: &SovereignCoin,
+    accrued_sovereign_amount: u64,
+    redeem_fee_bps: u16,
+    payment_decimals: u8,
+    payment_price: Option<(i128, u32)>,
+    usdc_depeg_price: Option<u64>,
+    collateral_ratio_bps: u64,
+    user_share_of_fiat_reserve: u64,
+    protocol_vault_balance: u64,
+) -> Result<RedeemQuote> {
+    let mid_usdc_amount = match (usdc_depeg_price, payment_price) {
+        (Some(usdc_price), _) => conversion::calculate_usdc_for_usd_amount_at_price(
+            accrued_sovereign_amount,
+            usdc_price,
+            payment_decimals,
+            sovereign_coin.decimals,
+        )?,
+        (None, Some((price_mantissa, price_scale))) => conversion::calculate_usdc_for_sovereign_amount_at_price(
+            accrued_sovereign_amount,
+            price_mantissa,
+            price_scale,
+            payment_decimals,
+            sovereign_coin.decimals,
+        )?,
+        (None, None) => conversion::convert_decimals(
+            accrued_sovereign_amount,
+            sovereign_coin.decimals,
+            payment_decimals,
+            Rounding::Down,
+        )?,
+    };
+
+    // The spread is withheld from the payout and booked for the spread recipient when the redemption executes
+    let spread_bps = sovereign_coin.redeem_spread_bps;
+    let spread_amount = calculate_percentage(mid_usdc_amount, spread_bps, Rounding::Up)?;
+    let quoted_usdc_amount = mid_usdc_amount.safe_sub(spread_amount)?;
+
+    // Early redeemers only take their share of what actually backs the coin
+    let usdc_amount = if collateral_ratio_bps < BASIS_POINT_MAX as u64 {
+        mul_div(quoted_usdc_amount, collateral_ratio_bps, BASIS_POINT_MAX as u64, Rounding::Down)?
+    } else {
+        quoted_usdc_amount
+    };
+    let collateral_haircut = quoted_usdc_amount.safe_sub(usdc_amount)?;
+    let oracle_rate = calculate_oracle_rate(payment_price, usdc_depeg_price)?;
+
+    let (net_amount, protocol_fee) = fee::calculate_protocol_fee(usdc_amount, redeem_fee_bps)?;
+
+    let from_usdc_reserve = utils::min(net_amount, user_share_of_fiat_reserve);
+    let remaining_after_fiat = net_amount.safe_sub(from_usdc_reserve)?;
+    let from_protocol_vault = if sovereign_coin.is_basket {
+        0
+    } else {
+        utils::min(remaining_after_fiat, protocol_vault_balance)
+    };
+    let from_bond_redemption = remaining_after_fiat.safe_sub(from_protocol_vault)?;
+
+    Ok(RedeemQuote {
+        usdc_amount,
+        spread_amount,
+        collateral_haircut,
+        net_amount,
+        protocol_fee,
+        from_usdc_reserve,
+        from_protocol_vault,
+        from_bond_redemption,
+        oracle_rate,
+        applied_rate: fee::calculate_spread_rate(oracle_rate, spread_bps, false)?,
+    })
+}
@@ -12,33 +12,83 @@ pub fn calculate_required_reserve(
     require!(bond_reserve_denominator > 0, StablecoinError::InvalidBondReserveRatio);
     
     // Convert to fixed point for precise math
-    let base = FixedPoint::new(base_bps as u128)
-        .ok_or(StablecoinError::MathError)?;
+    let base = FixedPoint::new(base_bps as i128);
     
     // Calculate ordinal factor (rating - 1)
-    let ordinal_factor = FixedPoint::new((bond_rating - 1) as u128)
-        .ok_or(StablecoinError::MathError)?;
+    let ordinal_factor = FixedPoint::new((bond_rating - 1) as i128);
     
     // Create numerator and denominator
-    let numerator = FixedPoint::new(bond_reserve_numerator as u128)
-        .ok_or(StablecoinError::MathError)?;
-    let denominator = FixedPoint::new(bond_reserve_denominator as u128)
-        .ok_or(StablecoinError::MathError)?;
+    let numerator = FixedPoint::new(bond_reserve_numerator as i128);
+    let denominator = FixedPoint::new(bond_reserve_denominator as i128);
     
-    // Calculate adjustment: (rating - 1) * (numerator / denominator)
-    let ratio = numerator.checked_div(&denominator)?;
-    let adjustment = ordinal_factor.checked_mul(&ratio)?;
+    // Calculate adjustment: (rating - 1) * numerator / denominator, multiplying first so only
+    // the final division rounds, and up so the protocol never holds less reserve than the curve asks
+    let adjustment = ordinal_factor
+        .checked_mul(&numerator, Rounding::Up)?
+        .checked_div(&denominator, Rounding::Up)?;
     
     // Calculate total percentage: base + adjustment
     let total = base.checked_add(&adjustment)?;
     
     // Convert back to basis points
-    let result_bps = total.to_u64()?;
+    let result_bps = total.to_u64(Rounding::Up)?;
     require!(result_bps <= BASIS_POINT_MAX as u64, StablecoinError::ReserveExceeds100Percent);
     
     Ok(result_bps as u16)
 }
 
+/// Calculate required reserve percentage from the configured reserve policy.
+/// `currency_backing` is the USDC value backing every coin in the same fiat currency, so
+/// currencies with very different unit values are weighed by what they are worth.
+pub fn calculate_policy_reserve(
+    policy: &ReservePolicy,
+    factory: &Factory,
+    bond_rating: u8,
+    currency_backing: u128,
+) -> Result<u16> {
+    require!(bond_rating >= 1 && bond_rating <= 10, StablecoinError::InvalidBondRating);
+
+    let curve = match policy.curve_kind {
+        ReserveCurveKind::Linear => FixedPoint::from_bps(calculate_required_reserve(
+            factory.min_usdc_reserve_percentage,
+            bond_rating,
+            factory.bond_reserve_numerator,
+            factory.bond_reserve_denominator,
+        )?),
+        ReserveCurveKind::StepTable => FixedPoint::from_bps(policy.rating_steps_bps[(bond_rating - 1) as usize]),
+        ReserveCurveKind::Convex => {
+            let ordinal_squared = FixedPoint::new((bond_rating - 1) as i128).checked_pow(2, Rounding::Up)?;
+            FixedPoint::from_bps(policy.convex_coefficient_bps)
+                .checked_mul(&ordinal_squared, Rounding::Up)?
+                .checked_add(&FixedPoint::from_bps(factory.min_usdc_reserve_percentage))?
+        }
+    };
+
+    // Coins of a currency that dominates the protocol's backing carry extra reserve once its share
+    // reaches the threshold, the share is rounded up so a fraction of a basis point under it counts as at it
+    let total_backing = i128::try_from(factory.total_backing_value).map_err(|_| error!(StablecoinError::MathError))?;
+    let concentration = if total_backing > 0 {
+        FixedPoint::new(i128::try_from(currency_backing).map_err(|_| error!(StablecoinError::MathError))?)
+            .rescale(4, Rounding::Up)?
+            .checked_div(&FixedPoint::new(total_backing), Rounding::Up)?
+    } else {
+        FixedPoint::from_bps(0)
+    };
+    let surcharge = if !concentration.checked_sub(&FixedPoint::from_bps(policy.concentration_threshold_bps))?.is_negative() {
+        FixedPoint::from_bps(policy.concentration_surcharge_bps)
+    } else {
+        FixedPoint::from_bps(0)
+    };
+
+    let result_bps = curve
+        .checked_add(&surcharge)?
+        .to_amount(4, Rounding::Up)?
+        .clamp(policy.floor_bps as u64, policy.cap_bps as u64);
+    require!(result_bps <= BASIS_POINT_MAX as u64, StablecoinError::ReserveExceeds100Percent);
+
+    Ok(result_bps as u16)
+}
+
 /// Calculate reserve and bond amounts for mint
 pub fn calculate_reserve_and_bond_amounts(
     net_amount: u64,
@@ -46,10 +96,11 @@ pub fn calculate_reserve_and_bond_amounts(
 ) -> Result<(u64, u64)> {
     require!(required_reserve_percentage <= BASIS_POINT_MAX, StablecoinError::InvalidReservePercentage);
     
-    // Calculate reserve amount: net_amount * required_reserve_percentage / BASIS_POINT_MAX
-    let reserve_amount = (net_amount as u128)
-        .safe_mul(required_reserve_percentage as u128)?
-        .safe_div(BASIS_POINT_MAX as u128)? as u64;
+    // Calculate reserve amount: net_amount * required_reserve_percentage / BASIS_POINT_MAX,
+    // the reserve keeps the rounding dust since it is the liquid side of the split
+    let reserve_amount = FixedPoint::new(net_amount as i128)
+        .checked_mul(&FixedPoint::from_bps(required_reserve_percentage), Rounding::Up)?
+        .to_u64(Rounding::Up)?;
     
     // Calculate bond amount: net_amount - reserve_amount
     let bond_amount = net_amount.safe_sub(reserve_amount)?;
@@ -59,3 +110,96 @@ pub fn calculate_reserve_and_bond_amounts(
     Ok((reserve_amount, bond_amount))
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factory(total_backing_value: u128) -> Factory {
+        Factory {
+            bump: 0,
+            authority: Pubkey::default(),
+            treasury: Pubkey::default(),
+            total_sovereign_coins: 0,
+            total_supply_all_coins: 0,
+            bond_rating_ordinals: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            global_usdc_reserve: Pubkey::default(),
+            global_usdc_account: Pubkey::default(),
+            min_usdc_reserve_percentage: 2000,
+            bond_reserve_numerator: 30,
+            bond_reserve_denominator: 9,
+            yield_share_protocol: 1000,
+            yield_share_issuer: 2000,
+            yield_share_holders: 7000,
+            transfer_fee_bps: 0,
+            maximum_transfer_fee: 0,
+            protocol_vault: Pubkey::default(),
+            bond_mappings_count: 0,
+            bond_mappings: [BondCurrencyMapping::default(); MAX_BOND_MAPPINGS],
+            payment_base_price_feed_account: Pubkey::default(),
+            payment_quote_price_feed_account: None,
+            total_backing_value,
+        }
+    }
+
+    fn policy(curve_kind: ReserveCurveKind) -> ReservePolicy {
+        ReservePolicy {
+            bump: 0,
+            curve_kind,
+            rating_steps_bps: [0; 10],
+            convex_coefficient_bps: 50,
+            floor_bps: 0,
+            cap_bps: BASIS_POINT_MAX,
+            concentration_threshold_bps: 3000,
+            concentration_surcharge_bps: 500,
+            min_mint_collateral_ratio_bps: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn linear_curve_adds_the_rating_ratio_rounded_up() {
+        let policy = policy(ReserveCurveKind::Linear);
+        let factory = factory(0);
+
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 1, 0).unwrap(), 2000);
+        // 30 / 9 of a basis point per rating step, a partial basis point rounds up
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 2, 0).unwrap(), 2004);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 4, 0).unwrap(), 2010);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 10, 0).unwrap(), 2030);
+    }
+
+    #[test]
+    fn convex_curve_grows_with_the_square_of_the_rating() {
+        let mut policy = policy(ReserveCurveKind::Convex);
+        let factory = factory(0);
+
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 1, 0).unwrap(), 2000);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 3, 0).unwrap(), 2200);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 10, 0).unwrap(), 6050);
+
+        // The cap still bounds the curve
+        policy.cap_bps = 2100;
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 3, 0).unwrap(), 2100);
+    }
+
+    #[test]
+    fn concentration_surcharge_starts_at_the_threshold() {
+        let policy = policy(ReserveCurveKind::Linear);
+        let factory = factory(10_000);
+
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 1, 2_999).unwrap(), 2000);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 1, 3_000).unwrap(), 2500);
+        assert_eq!(calculate_policy_reserve(&policy, &factory, 1, 3_001).unwrap(), 2500);
+    }
+
+    #[test]
+    fn concentration_share_rounds_up_into_the_threshold() {
+        let policy = policy(ReserveCurveKind::Linear);
+
+        // 9,000 of 30,001 is 2999.9 bps
+        assert_eq!(calculate_policy_reserve(&policy, &factory(30_001), 1, 9_000).unwrap(), 2500);
+        // With nothing backed yet no currency is concentrated
+        assert_eq!(calculate_policy_reserve(&policy, &factory(0), 1, 0).unwrap(), 2000);
+    }
+}
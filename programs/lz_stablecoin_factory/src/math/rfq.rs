@@ -0,0 +1,289 @@
+use super::*;
+
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+// Layout of an ed25519 program instruction: a signature count and padding byte,
+// then one 14 byte offsets entry per signature
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+
+/// Check that the instruction right before the current one is an ed25519 verification of
+/// `message` by `signer`, with the key, signature and message all inside that instruction
+pub fn verify_ed25519_signature(instructions: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    let current_index = load_current_index_checked(instructions)?;
+    require!(current_index > 0, StablecoinError::InvalidRfqSignature);
+
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, instructions)?;
+    require_keys_eq!(ed25519_ix.program_id, ed25519_program::ID, StablecoinError::InvalidRfqSignature);
+
+    check_ed25519_instruction_data(&ed25519_ix.data, signer, message)
+}
+
+/// Check that ed25519 instruction data verifies exactly one signature of `message` by `signer`
+fn check_ed25519_instruction_data(data: &[u8], signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(
+        data.len() >= ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE && data[0] == 1,
+        StablecoinError::InvalidRfqSignature
+    );
+
+    let read_u16 = |index: usize| -> u16 {
+        let at = ED25519_OFFSETS_START + index * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let public_key_offset = read_u16(2) as usize;
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+
+    // An offset pointing at another instruction would let the signed bytes differ from the ones checked here
+    require!(
+        read_u16(1) == u16::MAX && read_u16(3) == u16::MAX && read_u16(6) == u16::MAX,
+        StablecoinError::InvalidRfqSignature
+    );
+
+    let public_key = data.get(public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE)
+        .ok_or(StablecoinError::InvalidRfqSignature)?;
+    let signed_message = data.get(message_offset..message_offset + message_size)
+        .ok_or(StablecoinError::InvalidRfqSignature)?;
+    require!(
+        public_key == signer.as_ref() && signed_message == message,
+        StablecoinError::InvalidRfqSignature
+    );
+
+    Ok(())
+}
+
+/// Accept a signed quote for this trade on the quote signer's coin, returning the price it fills at.
+/// The nonce is burned by the caller creating its RfqNonce account.
+pub fn verify_rfq_quote(
+    quote: &RfqQuote,
+    quote_signer: &RfqQuoteSigner,
+    instructions: &AccountInfo,
+    taker: &Pubkey,
+    side: RfqSide,
+    amount: u64,
+    oracle_price: (i128, u32),
+    price_history: &PriceHistory,
+    unix_timestamp: i64,
+) -> Result<(i128, u32)> {
+    check_quote_terms(quote, quote_signer, taker, side, amount, unix_timestamp)?;
+    let twap = price_history.twap(price_history.twap_window, unix_timestamp)?;
+    let price = check_quote_price(quote, quote_signer, oracle_price, twap)?;
+
+    verify_ed25519_signature(instructions, &quote_signer.signer, &quote.try_to_vec()?)?;
+
+    Ok(price)
+}
+
+/// Check the quote was issued for this coin, taker, side and amount and is still open
+fn check_quote_terms(
+    quote: &RfqQuote,
+    quote_signer: &RfqQuoteSigner,
+    taker: &Pubkey,
+    side: RfqSide,
+    amount: u64,
+    unix_timestamp: i64,
+) -> Result<()> {
+    require!(quote_signer.is_enabled, StablecoinError::RfqQuotesDisabled);
+    require!(
+        quote.sovereign_coin == quote_signer.sovereign_coin
+            && quote.taker == *taker
+            && quote.side == side
+            && quote.amount == amount,
+        StablecoinError::InvalidRfqQuote
+    );
+    require!(unix_timestamp <= quote.expiry, StablecoinError::RfqQuoteExpired);
+
+    Ok(())
+}
+
+/// Check the quoted rate is within the signer's band of both the oracle price and, once
+/// there is one, the TWAP, so a leaked or careless signer can't fill far off market
+fn check_quote_price(
+    quote: &RfqQuote,
+    quote_signer: &RfqQuoteSigner,
+    oracle_price: (i128, u32),
+    twap: Option<i128>,
+) -> Result<(i128, u32)> {
+    let price = quote.payment_price()?;
+    let (quoted, _) = switchboard::normalize_decimal(price.0, price.1)?;
+    let (oracle, _) = switchboard::normalize_decimal(oracle_price.0, oracle_price.1)?;
+
+    for reference in std::iter::once(oracle).chain(twap) {
+        require!(
+            is_within_deviation(quoted, reference, quote_signer.max_oracle_deviation_bps)?,
+            StablecoinError::RfqQuoteOffMarket
+        );
+    }
+
+    Ok(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i128 = 1_000_000_000_000_000_000;
+
+    // Single signature ed25519 data laid out as the ed25519 program builds it: offsets, key, signature, message
+    fn ed25519_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = (ED25519_OFFSETS_START + ED25519_OFFSETS_SIZE) as u16;
+        let signature_offset = public_key_offset + ED25519_PUBKEY_SIZE as u16;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for field in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    fn quote_signer(sovereign_coin: Pubkey) -> RfqQuoteSigner {
+        RfqQuoteSigner {
+            bump: 0,
+            sovereign_coin,
+            signer: Pubkey::new_unique(),
+            is_enabled: true,
+            max_oracle_deviation_bps: 100,
+            updated_at: 0,
+        }
+    }
+
+    // 17.25 MXN per USDC
+    fn quote(sovereign_coin: Pubkey, taker: Pubkey) -> RfqQuote {
+        RfqQuote {
+            sovereign_coin,
+            taker,
+            side: RfqSide::Mint,
+            amount: 1_000_000,
+            rate: 17_250_000_000,
+            expiry: 100,
+            nonce: 7,
+        }
+    }
+
+    #[test]
+    fn ed25519_data_with_the_signer_and_message_inline_is_accepted() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"quote", u16::MAX);
+
+        assert!(check_ed25519_instruction_data(&data, &signer, b"quote").is_ok());
+    }
+
+    #[test]
+    fn ed25519_data_pointing_at_another_instruction_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"quote", 0);
+
+        assert_eq!(
+            check_ed25519_instruction_data(&data, &signer, b"quote").unwrap_err(),
+            StablecoinError::InvalidRfqSignature.into()
+        );
+    }
+
+    #[test]
+    fn ed25519_data_for_another_key_or_message_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"quote", u16::MAX);
+
+        assert!(check_ed25519_instruction_data(&data, &Pubkey::new_unique(), b"quote").is_err());
+        assert!(check_ed25519_instruction_data(&data, &signer, b"other").is_err());
+    }
+
+    #[test]
+    fn malformed_ed25519_data_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_data(&signer, b"quote", u16::MAX);
+
+        // More than one signature, or too short to hold the offsets
+        let mut multi = data.clone();
+        multi[0] = 2;
+        assert!(check_ed25519_instruction_data(&multi, &signer, b"quote").is_err());
+        assert!(check_ed25519_instruction_data(&data[..8], &signer, b"quote").is_err());
+
+        // Message running past the end of the data
+        let truncated = &data[..data.len() - 1];
+        assert!(check_ed25519_instruction_data(truncated, &signer, b"quote").is_err());
+    }
+
+    #[test]
+    fn quote_terms_must_match_the_trade() {
+        let sovereign_coin = Pubkey::new_unique();
+        let taker = Pubkey::new_unique();
+        let signer = quote_signer(sovereign_coin);
+        let quote = quote(sovereign_coin, taker);
+
+        assert!(check_quote_terms(&quote, &signer, &taker, RfqSide::Mint, 1_000_000, 50).is_ok());
+        assert!(check_quote_terms(&quote, &signer, &Pubkey::new_unique(), RfqSide::Mint, 1_000_000, 50).is_err());
+        assert!(check_quote_terms(&quote, &signer, &taker, RfqSide::Redeem, 1_000_000, 50).is_err());
+        assert!(check_quote_terms(&quote, &signer, &taker, RfqSide::Mint, 999_999, 50).is_err());
+        assert!(check_quote_terms(&quote, &quote_signer(Pubkey::new_unique()), &taker, RfqSide::Mint, 1_000_000, 50).is_err());
+    }
+
+    #[test]
+    fn quote_fills_up_to_its_expiry_and_only_while_enabled() {
+        let sovereign_coin = Pubkey::new_unique();
+        let taker = Pubkey::new_unique();
+        let mut signer = quote_signer(sovereign_coin);
+        let quote = quote(sovereign_coin, taker);
+
+        assert!(check_quote_terms(&quote, &signer, &taker, RfqSide::Mint, 1_000_000, 100).is_ok());
+        assert_eq!(
+            check_quote_terms(&quote, &signer, &taker, RfqSide::Mint, 1_000_000, 101).unwrap_err(),
+            StablecoinError::RfqQuoteExpired.into()
+        );
+
+        signer.is_enabled = false;
+        assert_eq!(
+            check_quote_terms(&quote, &signer, &taker, RfqSide::Mint, 1_000_000, 50).unwrap_err(),
+            StablecoinError::RfqQuotesDisabled.into()
+        );
+    }
+
+    #[test]
+    fn quote_price_is_bounded_by_the_oracle() {
+        let sovereign_coin = Pubkey::new_unique();
+        let signer = quote_signer(sovereign_coin);
+        let mut quote = quote(sovereign_coin, Pubkey::new_unique());
+
+        // Filled at the quoted rate, whatever scale the oracle reports at
+        assert_eq!(
+            check_quote_price(&quote, &signer, (17_250_000, 6), None).unwrap(),
+            (17_250_000_000, PRICE_PRECISION_DECIMALS as u32)
+        );
+
+        // 1% above a 17.00 oracle is the last rate accepted
+        quote.rate = 17_170_000_000;
+        assert!(check_quote_price(&quote, &signer, (17 * ONE, ORACLE_PRICE_SCALE), None).is_ok());
+        quote.rate = 17_170_000_001;
+        assert_eq!(
+            check_quote_price(&quote, &signer, (17 * ONE, ORACLE_PRICE_SCALE), None).unwrap_err(),
+            StablecoinError::RfqQuoteOffMarket.into()
+        );
+        quote.rate = 16_830_000_000;
+        assert!(check_quote_price(&quote, &signer, (17 * ONE, ORACLE_PRICE_SCALE), None).is_ok());
+    }
+
+    #[test]
+    fn quote_price_is_bounded_by_the_twap_when_there_is_one() {
+        let sovereign_coin = Pubkey::new_unique();
+        let signer = quote_signer(sovereign_coin);
+        let quote = quote(sovereign_coin, Pubkey::new_unique());
+
+        // In line with the oracle but 5% off the TWAP
+        assert!(check_quote_price(&quote, &signer, (17_250_000_000, 9), Some(ONE * 1815 / 100)).is_err());
+        assert!(check_quote_price(&quote, &signer, (17_250_000_000, 9), Some(ONE * 1730 / 100)).is_ok());
+    }
+}
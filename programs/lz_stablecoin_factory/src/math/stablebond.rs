@@ -0,0 +1,130 @@
+use super::*;
+
+/// Programs every Stablebond CPI needs alongside its own accounts
+pub struct StablebondPrograms<'a, 'info> {
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub token_2022_program: &'a AccountInfo<'info>,
+    pub associated_token_program: &'a AccountInfo<'info>,
+}
+
+/// Buy `amount` of a Stablebond with USDC from `payment_token_account`, signed by the factory
+pub fn purchase_bond_signed<'info>(
+    factory: &Account<'info, Factory>,
+    factory_signer: &[&[&[u8]]],
+    bond_holding: &AccountInfo<'info>,
+    payment_token_account: &AccountInfo<'info>,
+    bond_mint: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    programs: &StablebondPrograms<'_, 'info>,
+    payment_feed_type: PaymentFeedType,
+    bond_issuance_number: u64,
+    amount: u64,
+) -> Result<()> {
+    let (bond_pda, _) = find_bond_pda(bond_mint.key());
+    let (issuance_pda, _) = find_issuance_pda(bond_pda, bond_issuance_number);
+    let (payment_pda, _) = find_payment_pda(issuance_pda);
+    let (payment_feed_pda, _) = find_payment_feed_pda(payment_feed_type);
+    let (kyc_pda, _) = find_kyc_pda(factory.key());
+    let issuance_payment_token_account = get_associated_token_address(&payment_pda, &usdc_mint.key());
+
+    let purchase_bond_ix = PurchaseBondV2 {
+        user_wallet: factory.key(),
+        user_token_account: bond_holding.key(),
+        user_payment_token_account: payment_token_account.key(),
+        bond_account: bond_pda,
+        issuance_account: issuance_pda,
+        payment_account: payment_pda,
+        payment_token_account: issuance_payment_token_account,
+        kyc_account: kyc_pda,
+        mint_account: bond_mint.key(),
+        payment_mint_account: usdc_mint.key(),
+        payment_feed_account: payment_feed_pda,
+        payment_base_price_feed_account: factory.payment_base_price_feed_account,
+        payment_quote_price_feed_account: factory.payment_quote_price_feed_account,
+        associated_token_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        token2022_program: spl_token_2022::id(),
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(PurchaseBondV2InstructionArgs { amount });
+
+    solana_program::program::invoke_signed(
+        &purchase_bond_ix,
+        &[
+            factory.to_account_info(),
+            bond_holding.clone(),
+            payment_token_account.clone(),
+            bond_mint.clone(),
+            usdc_mint.clone(),
+            programs.system_program.clone(),
+            programs.token_program.clone(),
+            programs.token_2022_program.clone(),
+            programs.associated_token_program.clone(),
+        ],
+        factory_signer,
+    )?;
+
+    Ok(())
+}
+
+/// Instantly sell `amount` of a Stablebond back for USDC paid into `payment_token_account`, signed by the factory
+pub fn instant_redeem_bond_signed<'info>(
+    factory: &Account<'info, Factory>,
+    factory_signer: &[&[&[u8]]],
+    bond_holding: &AccountInfo<'info>,
+    payment_token_account: &AccountInfo<'info>,
+    bond_mint: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    programs: &StablebondPrograms<'_, 'info>,
+    payment_feed_type: PaymentFeedType,
+    bond_issuance_number: u64,
+    amount: u64,
+) -> Result<()> {
+    let (bond_pda, _) = find_bond_pda(bond_mint.key());
+    let (issuance_pda, _) = find_issuance_pda(bond_pda, bond_issuance_number);
+    let (payment_feed_pda, _) = find_payment_feed_pda(payment_feed_type);
+    let (sell_liquidity_pda, _) = find_sell_liquidity_pda(bond_pda);
+    let sell_liquidity_token_account = get_associated_token_address(&sell_liquidity_pda, &usdc_mint.key());
+    let fee_collector_wallet_token_account = get_associated_token_address(&ETHERFUSE_FEE_COLLECTOR, &usdc_mint.key());
+
+    let instant_redemption_ix = InstantBondRedemption {
+        user_wallet: factory.key(),
+        user_bond_token_account: bond_holding.key(),
+        user_payment_token_account: payment_token_account.key(),
+        bond_account: bond_pda,
+        mint_account: bond_mint.key(),
+        issuance_account: issuance_pda,
+        payment_mint_account: usdc_mint.key(),
+        payment_feed_account: payment_feed_pda,
+        sell_liquidity_account: sell_liquidity_pda,
+        sell_liquidity_token_account,
+        fee_collector_wallet_token_account,
+        payment_base_price_feed_account: factory.payment_base_price_feed_account,
+        payment_quote_price_feed_account: factory.payment_quote_price_feed_account,
+        associated_token_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        token2022_program: spl_token_2022::id(),
+        system_program: solana_program::system_program::id(),
+    }
+    .instruction(InstantBondRedemptionInstructionArgs { amount });
+
+    solana_program::program::invoke_signed(
+        &instant_redemption_ix,
+        &[
+            factory.to_account_info(),
+            bond_holding.clone(),
+            payment_token_account.clone(),
+            bond_mint.clone(),
+            usdc_mint.clone(),
+            programs.token_program.clone(),
+            programs.token_2022_program.clone(),
+            programs.associated_token_program.clone(),
+            programs.system_program.clone(),
+        ],
+        factory_signer,
+    )
+    .map_err(|_| StablecoinError::InstantRedemptionFailed)?;
+
+    Ok(())
+}
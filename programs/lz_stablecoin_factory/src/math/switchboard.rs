@@ -1,37 +1,15 @@
 use super::*;
 
-/// Convert Switchboard Decimal to our manual mantissa/scale format
-fn decimal_to_mantissa_scale(decimal_str: &str) -> Result<(i128, u32)> {
-    // Switchboard Decimal comes as string like "1234.567890"
-    let parts: Vec<&str> = decimal_str.split('.').collect();
-    
-    let integer_part = parts[0].parse::<i128>()
-        .map_err(|_| StablecoinError::MathError)?;
-    
-    let (fractional_digits, scale) = if parts.len() > 1 {
-        let fractional_str = parts[1];
-        let scale = fractional_str.len() as u32;
-        let fractional_part = fractional_str.parse::<u128>()
-            .map_err(|_| StablecoinError::MathError)?;
-        (fractional_part, scale)
-    } else {
-        (0, 0)
-    };
-    
-    // Combine: mantissa = integer_part * 10^scale + fractional_part
-    let scale_multiplier = 10_i128.checked_pow(scale)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let mantissa = integer_part
-        .checked_mul(scale_multiplier)
-        .ok_or(StablecoinError::MathError)?
-        .checked_add(fractional_digits as i128)
-        .ok_or(StablecoinError::MathError)?;
-    
-    Ok((mantissa, scale))
+/// Restate a decimal's mantissa at ORACLE_PRICE_SCALE decimals. Digits past the fixed scale
+/// are rounded down, which keeps the sign of negative values intact.
+pub fn normalize_decimal(mantissa: i128, scale: u32) -> Result<(i128, u32)> {
+    let normalized = FixedPoint::try_from((mantissa, scale))?
+        .rescale(ORACLE_PRICE_SCALE, Rounding::Down)?;
+
+    Ok(normalized.into())
 }
 
-/// Get price data from Switchboard On-Demand oracle in our mantissa/scale format
+/// Get price data from Switchboard On-Demand oracle as a mantissa at ORACLE_PRICE_SCALE decimals
 pub fn get_oracle_price_on_demand(
     feed_account: &AccountInfo,
 ) -> Result<(i128, u32)> {
@@ -40,24 +18,25 @@ pub fn get_oracle_price_on_demand(
     let feed = PullFeedAccountData::parse(data)
         .map_err(|_| StablecoinError::InvalidPriceFeed)?;
     
-    // Use anchor's Clock but convert it to the right format
-    let anchor_clock = anchor_lang::prelude::Clock::get()
+    let decimal_value = feed.value(&solana_clock()?)
         .map_err(|_| StablecoinError::InvalidPriceFeed)?;
     
-    // Create a solana_program Clock from anchor Clock
-    let solana_clock = anchor_lang::solana_program::sysvar::clock::Clock {
+    // Read the decimal's parts directly, it is already an integer mantissa and a power of ten scale
+    normalize_decimal(decimal_value.mantissa(), decimal_value.scale())
+}
+
+// The Switchboard SDK takes a solana_program Clock rather than anchor's
+fn solana_clock() -> Result<anchor_lang::solana_program::sysvar::clock::Clock> {
+    let anchor_clock = anchor_lang::prelude::Clock::get()
+        .map_err(|_| StablecoinError::InvalidPriceFeed)?;
+
+    Ok(anchor_lang::solana_program::sysvar::clock::Clock {
         slot: anchor_clock.slot,
         epoch_start_timestamp: anchor_clock.epoch_start_timestamp,
         epoch: anchor_clock.epoch,
         leader_schedule_epoch: anchor_clock.leader_schedule_epoch,
         unix_timestamp: anchor_clock.unix_timestamp,
-    };
-    
-    let decimal_value = feed.value(&solana_clock)
-        .map_err(|_| StablecoinError::InvalidPriceFeed)?;
-    
-    let decimal_str = decimal_value.to_string();
-    decimal_to_mantissa_scale(&decimal_str)
+    })
 }
 
 
@@ -66,20 +45,17 @@ pub fn calculate_cross_price_on_demand(
     base_feed: &AccountInfo,   // USDC/USD
     quote_feed: &AccountInfo,  // USD/EUR (or other currency)
 ) -> Result<(i128, u32)> {
-    let (base_mantissa, base_scale) = get_oracle_price_on_demand(base_feed)?;
-    let (quote_mantissa, quote_scale) = get_oracle_price_on_demand(quote_feed)?;
-    
-    // Calculate combined scale
-    let result_scale = base_scale.checked_add(quote_scale)
-        .ok_or(StablecoinError::MathError)?;
-        
-    // Calculate combined mantissa (base * quote)
-    let result_mantissa = base_mantissa.checked_mul(quote_mantissa)
-        .ok_or(StablecoinError::MathError)?;
-    
-    Ok((result_mantissa, result_scale))
+    cross_price(get_oracle_price_on_demand(base_feed)?, get_oracle_price_on_demand(quote_feed)?)
 }
 
+/// Combined price (base * quote), kept at the base price's scale. Both prices sit at
+/// ORACLE_PRICE_SCALE, the product is widened so quotes in the thousands (COP, IDR, KRW) fit.
+pub fn cross_price(base_price: (i128, u32), quote_price: (i128, u32)) -> Result<(i128, u32)> {
+    let cross_price = FixedPoint::try_from(base_price)?
+        .checked_mul(&FixedPoint::try_from(quote_price)?, Rounding::Down)?;
+
+    Ok(cross_price.into())
+}
 
 /// Updated calculate_sovereign_coin_amount using On-Demand (mantissa/scale)
 pub fn calculate_sovereign_coin_amount_on_demand(
@@ -91,7 +67,7 @@ pub fn calculate_sovereign_coin_amount_on_demand(
 ) -> Result<u64> {
     let (price_mantissa, price_scale) = if target_currency == "USD" {
         // For USD sovereign coins, use 1:1 with USDC
-        return Ok(usdc_amount);
+        return conversion::convert_decimals(usdc_amount, USDC_DECIMALS, decimals, Rounding::Down);
     } else if let (Some(base_feed), Some(quote_feed)) = (base_price_feed, quote_price_feed) {
         // Calculate cross price
         calculate_cross_price_on_demand(base_feed, quote_feed)?
@@ -102,30 +78,7 @@ pub fn calculate_sovereign_coin_amount_on_demand(
         return Err(StablecoinError::InvalidPriceFeed.into());
     };
 
-    // Use your existing calculation logic
-    let usdc_scaled = (usdc_amount as u128)
-        .checked_mul(10u128.pow(decimals as u32))
-        .ok_or(StablecoinError::MathError)?;
-    
-    let sovereign_scaled = usdc_scaled
-        .checked_mul(price_mantissa.unsigned_abs() as u128)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let total_scale = price_scale.checked_add(decimals as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let divisor = 10u128.checked_pow(total_scale as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let sovereign_amount = sovereign_scaled
-        .checked_div(divisor)
-        .ok_or(StablecoinError::MathError)?;
-    
-    if sovereign_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
-    }
-    
-    Ok(sovereign_amount as u64)
+    conversion::calculate_sovereign_coin_amount_at_price(usdc_amount, price_mantissa, price_scale, USDC_DECIMALS, decimals)
 }
 
 pub fn calculate_bond_equivalent(
@@ -133,37 +86,213 @@ pub fn calculate_bond_equivalent(
     base_price_feed: &AccountInfo,
     quote_price_feed: Option<&AccountInfo>,
     target_currency: &str,
-    decimals: u8,
+    bond_decimals: u8,
 ) -> Result<u64> {
     let (price_mantissa, price_scale) = if target_currency == "USD" {
-        return Ok(usdc_amount);
+        return conversion::convert_decimals(usdc_amount, USDC_DECIMALS, bond_decimals, Rounding::Down);
     } else if let Some(quote_feed) = quote_price_feed {
         calculate_cross_price_on_demand(base_price_feed, quote_feed)?
     } else {
         get_oracle_price_on_demand(base_price_feed)?
     };
 
-    let usdc_scaled = (usdc_amount as u128)
-        .checked_mul(10u128.pow(decimals as u32))
-        .ok_or(StablecoinError::MathError)?;
-    
-    let bond_scaled = usdc_scaled
-        .checked_mul(price_mantissa.unsigned_abs() as u128)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let total_scale = price_scale.checked_add(decimals as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let divisor = 10u128.checked_pow(total_scale as u32)
-        .ok_or(StablecoinError::MathError)?;
-    
-    let bond_amount = bond_scaled
-        .checked_div(divisor)
-        .ok_or(StablecoinError::MathError)?;
-    
-    if bond_amount > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
+    // Bonds are denominated in the target currency, so this is the same conversion as a mint
+    conversion::calculate_sovereign_coin_amount_at_price(usdc_amount, price_mantissa, price_scale, USDC_DECIMALS, bond_decimals)
+}
+
+/// USDC value of `bond_amount` bond base units at a price of target currency per USDC,
+/// the inverse of `calculate_bond_equivalent`. Bonds are priced in the target currency, so the
+/// fiat amount is converted back at the price and rescaled from the bond's decimals to USDC's.
+pub fn calculate_bond_value(
+    bond_amount: u64,
+    price_mantissa: i128,
+    price_scale: u32,
+    bond_decimals: u8,
+) -> Result<u64> {
+    let price = FixedPoint::try_from((price_mantissa, price_scale))?;
+    require!(price.is_positive(), StablecoinError::InvalidPriceFeed);
+
+    // Marked down, collateral is never valued above what the price supports
+    FixedPoint::from_amount(bond_amount, bond_decimals)
+        .checked_div(&price, Rounding::Down)?
+        .to_amount(USDC_DECIMALS, Rounding::Down)
+}
+
+/// USDC/USD from the base payment feed, scaled by PRICE_PRECISION
+pub fn get_usdc_usd_price(base_price_feed: &AccountInfo) -> Result<u64> {
+    let (price_mantissa, price_scale) = get_oracle_price_on_demand(base_price_feed)?;
+    require!(price_mantissa > 0, StablecoinError::InvalidPriceFeed);
+
+    to_price_precision(price_mantissa, price_scale)
+}
+
+/// Express an oracle price with PRICE_PRECISION decimals
+pub fn to_price_precision(price_mantissa: i128, price_scale: u32) -> Result<u64> {
+    FixedPoint::try_from((price_mantissa, price_scale))?
+        .to_amount(PRICE_PRECISION_DECIMALS, Rounding::Down)
+}
+
+/// Compose a route of feeds into a single price with ORACLE_PRICE_SCALE decimals.
+/// Every leg is read at the oracle scale before it is multiplied in or divided out,
+/// so each leg rounds down by less than one unit at that scale and the error stays bounded by the leg count.
+pub fn calculate_route_price(legs: &[PriceFeedLeg], feed_accounts: &[AccountInfo]) -> Result<(i128, u32)> {
+    require!(feed_accounts.len() >= legs.len(), StablecoinError::InvalidPriceRoute);
+
+    compose_route_price(legs, |i| get_oracle_price_on_demand(&feed_accounts[i]))
+}
+
+/// Route price from each leg's feed price, `leg_price` is called with the leg's index
+fn compose_route_price(
+    legs: &[PriceFeedLeg],
+    leg_price: impl Fn(usize) -> Result<(i128, u32)>,
+) -> Result<(i128, u32)> {
+    require!(
+        !legs.is_empty() && legs.len() <= MAX_PRICE_ROUTE_LEGS,
+        StablecoinError::InvalidPriceRoute
+    );
+
+    let mut route_price = FixedPoint::new(1).rescale(ORACLE_PRICE_SCALE, Rounding::Down)?;
+    for (i, leg) in legs.iter().enumerate() {
+        let leg_price = FixedPoint::try_from(leg_price(i)?)?;
+        require!(leg_price.is_positive(), StablecoinError::InvalidPriceFeed);
+
+        route_price = if leg.invert {
+            route_price.checked_div(&leg_price, Rounding::Down)?
+        } else {
+            route_price.checked_mul(&leg_price, Rounding::Down)?
+        };
+        require!(route_price.is_positive(), StablecoinError::MathError);
     }
-    
-    Ok(bond_amount as u64)
-}
\ No newline at end of file
+
+    Ok(route_price.into())
+}
+
+/// Price of `target_currency` per USDC for a trade, None for USD coins which convert at par.
+/// Uses the configured route when the currency has one, otherwise the factory's base feed
+/// crossed with the optional quote feed.
+pub fn resolve_payment_price(
+    base_price_feed: &AccountInfo,
+    quote_price_feed: Option<&AccountInfo>,
+    price_route: Option<(&PriceRoute, &[AccountInfo])>,
+    target_currency: &str,
+) -> Result<Option<(i128, u32)>> {
+    let price = if target_currency == "USD" {
+        return Ok(None);
+    } else if let Some((route, feed_accounts)) = price_route {
+        route.resolve_price(feed_accounts)?
+    } else if let Some(quote_feed) = quote_price_feed {
+        calculate_cross_price_on_demand(base_price_feed, quote_feed)?
+    } else {
+        get_oracle_price_on_demand(base_price_feed)?
+    };
+
+    Ok(Some(price))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i128 = 1_000_000_000_000_000_000;
+
+    #[test]
+    fn normalize_negative_fraction() {
+        // "-0.5" as mantissa -5 with one decimal
+        assert_eq!(normalize_decimal(-5, 1).unwrap(), (-ONE / 2, ORACLE_PRICE_SCALE));
+    }
+
+    #[test]
+    fn normalize_scale_zero_scales_up() {
+        assert_eq!(normalize_decimal(7, 0).unwrap(), (7 * ONE, ORACLE_PRICE_SCALE));
+    }
+
+    #[test]
+    fn normalize_oracle_scale_is_unchanged() {
+        assert_eq!(
+            normalize_decimal(1_234_567_890_123_456_789, 18).unwrap(),
+            (1_234_567_890_123_456_789, ORACLE_PRICE_SCALE)
+        );
+    }
+
+    #[test]
+    fn normalize_scale_28_rounds_down() {
+        // 1.2345678901234567890123456789 loses its last ten digits
+        assert_eq!(
+            normalize_decimal(12_345_678_901_234_567_890_123_456_789, 28).unwrap(),
+            (1_234_567_890_123_456_789, ORACLE_PRICE_SCALE)
+        );
+        // Rounding down moves negative values away from zero
+        assert_eq!(normalize_decimal(-1, 28).unwrap(), (-1, ORACLE_PRICE_SCALE));
+    }
+
+    #[test]
+    fn normalize_i128_limits() {
+        // Scaling up past i128 fails instead of wrapping
+        assert!(normalize_decimal(i128::MAX, 0).is_err());
+        assert!(normalize_decimal(i128::MIN, 0).is_err());
+        // Scaling down from the widest mantissas works
+        assert_eq!(
+            normalize_decimal(i128::MAX, FixedPoint::MAX_SCALE).unwrap(),
+            (i128::MAX / 100_000_000_000_000_000_000, ORACLE_PRICE_SCALE)
+        );
+        assert_eq!(
+            normalize_decimal(i128::MIN, FixedPoint::MAX_SCALE).unwrap(),
+            (i128::MIN / 100_000_000_000_000_000_000 - 1, ORACLE_PRICE_SCALE)
+        );
+        // Scales beyond what i128 can carry are rejected
+        assert!(normalize_decimal(1, FixedPoint::MAX_SCALE + 1).is_err());
+    }
+
+    #[test]
+    fn cross_price_handles_large_quotes() {
+        let usdc_usd = (ONE + ONE / 10_000, ORACLE_PRICE_SCALE); // 1.0001
+        for quote in [4_100i128, 16_250, 1_380] {
+            let (price, scale) = cross_price(usdc_usd, (quote * ONE, ORACLE_PRICE_SCALE)).unwrap();
+            assert_eq!(scale, ORACLE_PRICE_SCALE);
+            assert_eq!(price, quote * ONE + quote * ONE / 10_000);
+        }
+    }
+
+    fn leg(invert: bool) -> PriceFeedLeg {
+        PriceFeedLeg { invert, ..Default::default() }
+    }
+
+    #[test]
+    fn route_with_an_inverted_leg() {
+        // USDC→USD at 1.0001 USD per USDC, then USD→EUR through a 1.08 USD per EUR feed walked backwards
+        let prices = [(1_000_100_000_000_000_000, 18), (108, 2)];
+        let price = compose_route_price(&[leg(false), leg(true)], |i| Ok(prices[i])).unwrap();
+
+        // 1.0001 / 1.08 = 0.92601851..., cut at the oracle scale
+        assert_eq!(price, (926_018_518_518_518_518, ORACLE_PRICE_SCALE));
+    }
+
+    #[test]
+    fn route_leg_count_is_bounded() {
+        assert!(compose_route_price(&[], |_| Ok((ONE, ORACLE_PRICE_SCALE))).is_err());
+        let legs = [leg(false); MAX_PRICE_ROUTE_LEGS + 1];
+        assert!(compose_route_price(&legs, |_| Ok((ONE, ORACLE_PRICE_SCALE))).is_err());
+    }
+
+    #[test]
+    fn route_rejects_prices_it_can_not_carry() {
+        // Two legs of 10^20 overflow i128 at the oracle scale
+        let price = compose_route_price(&[leg(false), leg(false)], |_| Ok((100_000_000_000_000_000_000, 0)));
+        assert!(price.is_err());
+
+        // Dividing out 10^19 leaves nothing at the oracle scale
+        let price = compose_route_price(&[leg(true)], |_| Ok((10_000_000_000_000_000_000, 0)));
+        assert_eq!(price.unwrap_err(), StablecoinError::MathError.into());
+
+        // Non-positive or unscalable leg prices
+        assert!(compose_route_price(&[leg(false)], |_| Ok((0, ORACLE_PRICE_SCALE))).is_err());
+        assert!(compose_route_price(&[leg(false)], |_| Ok((1, FixedPoint::MAX_SCALE + 1))).is_err());
+    }
+
+    #[test]
+    fn bond_value_at_large_prices() {
+        // 1,625,000 IDR bonds at 16,250 IDR per USDC are worth 100 USDC
+        let value = calculate_bond_value(1_625_000_000_000, 16_250 * ONE, ORACLE_PRICE_SCALE, 6).unwrap();
+        assert_eq!(value, 100_000_000);
+    }
+}
@@ -148,6 +148,191 @@ pub fn initialize_ibt_mint_with_transfer_fee<'info>(
     Ok(())
 }
 
+pub fn initialize_ibt_mint_with_token_metadata<'info>(
+    payer: &Signer<'info>,
+    mint_account: &Signer<'info>,
+    token_program: &Program<'info, Token2022>,
+    system_program: &Program<'info, System>,
+    factory_authority: &AccountInfo<'info>, // Factory PDA as ALL authorities
+    factory_signer: &[&[&[u8]]], // Factory PDA seeds
+    initial_rate: i16,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+    decimals: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    // Calculate space required for mint with all three fixed-size extensions
+    let mint_size = ExtensionType::try_calculate_account_len::<PodMint>(
+        &[
+            ExtensionType::InterestBearingConfig,
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MetadataPointer,
+        ]
+    )?;
+
+    // The metadata TLV entry is variable length and is reallocated by Token-2022
+    // during initialization, so only fund it here
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(factory_authority.key()))?,
+        mint: mint_account.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: uri.clone(),
+        additional_metadata: vec![],
+    };
+    let metadata_size = token_metadata.tlv_size_of()?;
+
+    let lamports = (Rent::get()?).minimum_balance(mint_size.safe_add(metadata_size)?);
+
+    create_account(
+        CpiContext::new(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: mint_account.to_account_info(),
+            },
+        ),
+        lamports,
+        mint_size as u64,
+        &token_program.key(),
+    )?;
+
+    interest_bearing_mint_initialize(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InterestBearingMintInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: mint_account.to_account_info(),
+            },
+        ),
+        Some(factory_authority.key()), // Factory as rate authority
+        initial_rate,
+    )?;
+
+    transfer_fee_initialize(
+        CpiContext::new(
+            token_program.to_account_info(),
+            TransferFeeInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: mint_account.to_account_info(),
+            },
+        ),
+        Some(&factory_authority.key()), // Factory as config authority
+        Some(&factory_authority.key()), // Factory as withdraw authority
+        transfer_fee_basis_points,
+        maximum_fee,
+    )?;
+
+    // Point the metadata at the mint itself
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.to_account_info(),
+                mint: mint_account.to_account_info(),
+            },
+        ),
+        Some(factory_authority.key()), // Factory as pointer authority
+        Some(mint_account.key()),
+    )?;
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: mint_account.to_account_info(),
+            },
+        ),
+        decimals,
+        &factory_authority.key(), // Factory as mint authority
+        Some(&factory_authority.key()), // Factory as freeze authority
+    )?;
+
+    // Metadata can only be written once the mint is initialized, and needs the mint authority
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataInitialize {
+                token_program_id: token_program.to_account_info(),
+                metadata: mint_account.to_account_info(),
+                update_authority: factory_authority.to_account_info(),
+                mint_authority: factory_authority.to_account_info(),
+                mint: mint_account.to_account_info(),
+            },
+            factory_signer,
+        ),
+        name,
+        symbol,
+        uri,
+    )?;
+
+    Ok(())
+}
+
+/// Read the Token-2022 metadata stored in a mint account
+pub fn read_token_metadata(mint: &AccountInfo) -> Result<TokenMetadata> {
+    let mint_data = mint.data.borrow();
+    let mint_with_extension = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let token_metadata = mint_with_extension.get_variable_len_extension::<TokenMetadata>()?;
+
+    Ok(token_metadata)
+}
+
+/// Update a Token-2022 metadata field (factory signs)
+/// Tops up the mint's lamports first so it stays rent exempt if the field grows
+pub fn update_token_metadata_field_signed<'info>(
+    payer: &Signer<'info>,
+    factory_authority: &AccountInfo<'info>,
+    factory_signer: &[&[&[u8]]], // Factory PDA seeds
+    mint_account: &AccountInfo<'info>,
+    token_program: &Program<'info, Token2022>,
+    system_program: &Program<'info, System>,
+    field: Field,
+    value: String,
+) -> Result<()> {
+    let mut token_metadata = read_token_metadata(mint_account)?;
+    let current_size = token_metadata.tlv_size_of()?;
+    token_metadata.update(field.clone(), value.clone());
+    let updated_size = token_metadata.tlv_size_of()?;
+
+    let new_account_len = mint_account.data_len()
+        .safe_add(updated_size)?
+        .safe_sub(current_size)?;
+    let required_lamports = (Rent::get()?).minimum_balance(new_account_len);
+    let current_lamports = mint_account.lamports();
+
+    if required_lamports > current_lamports {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: mint_account.to_account_info(),
+                },
+            ),
+            required_lamports.safe_sub(current_lamports)?,
+        )?;
+    }
+
+    token_metadata_update_field(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TokenMetadataUpdateField {
+                token_program_id: token_program.to_account_info(),
+                metadata: mint_account.to_account_info(),
+                update_authority: factory_authority.to_account_info(),
+            },
+            factory_signer,
+        ),
+        field,
+        value,
+    )?;
+
+    Ok(())
+}
+
 /// Read transfer fee config from a mint account
 pub fn read_transfer_fee_config(mint: &AccountInfo) -> Result<TransferFeeConfig> {
     let mint_data = mint.data.borrow();
@@ -167,6 +352,18 @@ pub fn calculate_transfer_fee_from_mint(mint: &AccountInfo, amount: u64) -> Resu
         .ok_or(error!(StablecoinError::MathError))
 }
 
+/// Fee a transfer of `amount` would pay this epoch, zero for mints without a transfer fee
+pub fn expected_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.data.borrow();
+    let mint_with_extension = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+    let Ok(fee_config) = mint_with_extension.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    fee_config.calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(error!(StablecoinError::MathError))
+}
+
 /// Transfer tokens with fee calculation
 pub fn transfer_with_fee<'info>(
     token_program: &Program<'info, Token2022>,
@@ -479,4 +676,50 @@ pub fn check_interest_bearing_mint_data(mint_account_info: &AccountInfo, authori
 pub fn read_current_interest_rate(mint_account_info: &AccountInfo) -> Result<i16> {
     let config = read_interest_bearing_config(mint_account_info)?;
     Ok(config.current_rate.into())
-}
\ No newline at end of file
+}
+// Decimal places the interest-bearing growth factor is computed at
+const INTEREST_SCALE_DECIMALS: u32 = 18;
+
+/// Growth factor of an interest-bearing mint at `unix_timestamp`, the continuous compounding
+/// Token-2022 applies: the pre-update average rate from initialization to the last rate change,
+/// then the current rate since, exp(rate * elapsed / year) for each span
+pub fn interest_bearing_scale(config: &InterestBearingConfig, unix_timestamp: i64, rounding: Rounding) -> Result<FixedPoint> {
+    let initialized_at = i64::from(config.initialization_timestamp);
+    let last_update = i64::from(config.last_update_timestamp);
+    let pre_update_rate = i16::from(config.pre_update_average_rate) as i128;
+    let current_rate = i16::from(config.current_rate) as i128;
+
+    // Both spans in basis point seconds, the two exponentials multiply into one of their sum
+    let rate_seconds = pre_update_rate
+        .safe_mul(last_update.safe_sub(initialized_at)? as i128)?
+        .safe_add(current_rate.safe_mul(unix_timestamp.safe_sub(last_update)? as i128)?)?;
+    let basis_point_years = INTEREST_BEARING_SECONDS_PER_YEAR.safe_mul(BASIS_POINT_MAX as i128)?;
+
+    FixedPoint::new(rate_seconds)
+        .rescale(INTEREST_SCALE_DECIMALS, rounding)?
+        .checked_div(&FixedPoint::new(basis_point_years), rounding)?
+        .checked_exp(rounding)
+}
+
+/// Convert a raw amount of an interest-bearing mint into its accrued (UI) amount,
+/// expressed in base units. Rounds down so holders are never paid ahead of accrual.
+pub fn raw_amount_to_accrued_amount(mint_account_info: &AccountInfo, raw_amount: u64, unix_timestamp: i64) -> Result<u64> {
+    let config = read_interest_bearing_config(mint_account_info)?;
+    let scale = interest_bearing_scale(&config, unix_timestamp, Rounding::Down)?;
+
+    FixedPoint::new(raw_amount as i128)
+        .checked_mul(&scale, Rounding::Down)?
+        .to_u64(Rounding::Down)
+}
+
+/// Convert an accrued (UI) amount in base units back into the raw amount of an interest-bearing mint.
+/// Never returns more raw tokens than the accrued amount is worth.
+pub fn accrued_amount_to_raw_amount(mint_account_info: &AccountInfo, accrued_amount: u64, unix_timestamp: i64) -> Result<u64> {
+    let config = read_interest_bearing_config(mint_account_info)?;
+    // An upper bound on the growth keeps the raw amount a lower bound
+    let scale = interest_bearing_scale(&config, unix_timestamp, Rounding::Up)?;
+
+    FixedPoint::new(accrued_amount as i128)
+        .checked_div(&scale, Rounding::Down)?
+        .to_u64(Rounding::Down)
+}
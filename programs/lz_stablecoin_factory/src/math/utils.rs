@@ -10,39 +10,20 @@ pub enum Rounding {
 }
 
 /// Calculate percentage of a value
-pub fn calculate_percentage(value: u64, percentage_bps: u16) -> Result<u64> {
-    let result = (value as u128)
-        .safe_mul(percentage_bps as u128)?
-        .safe_div(10_000)?;
-        
-    if result > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
-    }
-    
-    Ok(result as u64)
+pub fn calculate_percentage(value: u64, percentage_bps: u16, rounding: Rounding) -> Result<u64> {
+    FixedPoint::new(value as i128)
+        .checked_mul(&FixedPoint::from_bps(percentage_bps), rounding)?
+        .to_u64(rounding)
 }
 
-/// Multiply and then divide, with optional rounding
+/// Multiply and then divide, rounding once at the end
 pub fn mul_div(x: u64, y: u64, denominator: u64, rounding: Rounding) -> Result<u64> {
     require!(denominator > 0, StablecoinError::DivisionByZero);
-    
-    let prod = (x as u128).safe_mul(y as u128)?;
-    
-    let result = match rounding {
-        Rounding::Up => {
-            let adjusted = prod.safe_add(denominator as u128 - 1)?;
-            adjusted.safe_div(denominator as u128)?
-        },
-        Rounding::Down => {
-            prod.safe_div(denominator as u128)?
-        }
-    };
-    
-    if result > u64::MAX as u128 {
-        return Err(StablecoinError::MathError.into());
-    }
-    
-    Ok(result as u64)
+
+    FixedPoint::new(x as i128)
+        .checked_mul(&FixedPoint::new(y as i128), rounding)?
+        .checked_div(&FixedPoint::new(denominator as i128), rounding)?
+        .to_u64(rounding)
 }
 
 /// Calculate minimum of two values
@@ -0,0 +1,137 @@
+use super::*;
+
+/// Live view of what backs a sovereign coin, all amounts in USDC
+pub struct CollateralMark {
+    pub bond_value: u64,        // Bond holdings marked to market
+    pub liability: u64,         // Outstanding supply at the oracle rate
+    pub collateral_ratio_bps: u64,
+}
+
+/// USDC value of `bond_amount` raw units of a Stablebond. The bond's accrued interest is applied
+/// first, then its face value is priced at the payment price, None meaning the currency trades at par.
+///
+/// This is the price Etherfuse settles at. Its payment feed converts the bond's face value through
+/// the factory's own base and quote price feeds, which every purchase and redemption CPI passes next
+/// to the payment feed PDA and Etherfuse checks against it. `payment_price` is read from those same
+/// feeds, and the mint's interest-bearing extension is how Etherfuse accrues the bond's coupon, so
+/// the accrued amount priced here is what an instant redemption would pay before Etherfuse's fee.
+pub fn mark_bond_to_market(
+    bond_mint: &AccountInfo,
+    bond_decimals: u8,
+    bond_amount: u64,
+    payment_price: Option<(i128, u32)>,
+    unix_timestamp: i64,
+) -> Result<u64> {
+    if bond_amount == 0 {
+        return Ok(0);
+    }
+
+    let accrued_bond_amount = token_extension::raw_amount_to_accrued_amount(bond_mint, bond_amount, unix_timestamp)?;
+    let (price_mantissa, price_scale) = payment_price.unwrap_or((1, 0));
+
+    switchboard::calculate_bond_value(accrued_bond_amount, price_mantissa, price_scale, bond_decimals)
+}
+
+/// Raw Stablebond units to sell for `usdc_value` USDC, the inverse of `mark_bond_to_market`.
+/// The value is converted into the bond's currency at the payment price and the bond's accrued
+/// interest is then taken back out, both rounding down so the sale never exceeds the value.
+pub fn bond_amount_for_value(
+    bond_mint: &AccountInfo,
+    bond_decimals: u8,
+    usdc_value: u64,
+    payment_price: Option<(i128, u32)>,
+    unix_timestamp: i64,
+) -> Result<u64> {
+    if usdc_value == 0 {
+        return Ok(0);
+    }
+
+    let accrued_bond_amount = match payment_price {
+        Some((price_mantissa, price_scale)) => conversion::calculate_sovereign_coin_amount_at_price(
+            usdc_value,
+            price_mantissa,
+            price_scale,
+            USDC_DECIMALS,
+            bond_decimals,
+        )?,
+        None => conversion::convert_decimals(usdc_value, USDC_DECIMALS, bond_decimals, Rounding::Down)?,
+    };
+
+    token_extension::accrued_amount_to_raw_amount(bond_mint, accrued_bond_amount, unix_timestamp)
+}
+
+/// (reserve + bond value) / liability in basis points. A coin with no supply owes nothing
+/// and reports u64::MAX so it never trips a minimum.
+pub fn calculate_collateral_ratio(usdc_reserve: u64, bond_value: u64, liability: u64) -> Result<u64> {
+    if liability == 0 {
+        return Ok(u64::MAX);
+    }
+
+    let ratio = (usdc_reserve as u128)
+        .safe_add(bond_value as u128)?
+        .safe_mul(BASIS_POINT_MAX as u128)?
+        .safe_div(liability as u128)?;
+
+    Ok(u64::try_from(ratio).unwrap_or(u64::MAX))
+}
+
+/// Mark a coin's bonds to market and compare them, with the USDC reserve, to its outstanding supply.
+/// Basket coins value every constituent from the (bond mint, bond holding) pairs at the start of the remaining accounts.
+pub fn mark_collateral<'info>(
+    sovereign_coin: &SovereignCoin,
+    coin_mint: &AccountInfo<'info>,
+    bond_mint: &InterfaceAccount<'info, Mint>,
+    bond_holding: &InterfaceAccount<'info, TokenAccount>,
+    bond_basket: Option<&Account<'info, BondBasket>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    payment_price: Option<(i128, u32)>,
+    unix_timestamp: i64,
+) -> Result<CollateralMark> {
+    let bond_value = if sovereign_coin.is_basket {
+        let bond_basket = bond_basket.ok_or(StablecoinError::BondBasketRequired)?;
+        let mut total_value = 0u64;
+        for (constituent_mint, constituent_holding) in bond_basket.constituent_accounts(remaining_accounts)? {
+            let decimals = InterfaceAccount::<Mint>::try_from(constituent_mint)?.decimals;
+            let amount = InterfaceAccount::<TokenAccount>::try_from(constituent_holding)?.amount;
+            total_value = total_value.safe_add(mark_bond_to_market(
+                constituent_mint,
+                decimals,
+                amount,
+                payment_price,
+                unix_timestamp,
+            )?)?;
+        }
+        total_value
+    } else {
+        mark_bond_to_market(
+            &bond_mint.to_account_info(),
+            bond_mint.decimals,
+            bond_holding.amount,
+            payment_price,
+            unix_timestamp,
+        )?
+    };
+
+    // Holders are owed the accrued amount of an interest-bearing coin
+    let accrued_supply = if sovereign_coin.is_interest_bearing {
+        token_extension::raw_amount_to_accrued_amount(coin_mint, sovereign_coin.total_supply, unix_timestamp)?
+    } else {
+        sovereign_coin.total_supply
+    };
+    let liability = match payment_price {
+        Some((price_mantissa, price_scale)) => conversion::calculate_usdc_for_sovereign_amount_at_price(
+            accrued_supply,
+            price_mantissa,
+            price_scale,
+            USDC_DECIMALS,
+            sovereign_coin.decimals,
+        )?,
+        None => conversion::convert_decimals(accrued_supply, sovereign_coin.decimals, USDC_DECIMALS, Rounding::Down)?,
+    };
+
+    Ok(CollateralMark {
+        bond_value,
+        liability,
+        collateral_ratio_bps: calculate_collateral_ratio(sovereign_coin.usdc_amount, bond_value, liability)?,
+    })
+}
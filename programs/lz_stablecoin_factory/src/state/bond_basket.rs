@@ -0,0 +1,116 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct BasketConstituent {
+    pub bond_mint: Pubkey,           // The Stablebond token mint
+    pub bond_holding: Pubkey,        // Factory owned token account holding this bond for the coin holders
+    pub bond_issuance_number: u64,
+    pub weight_bps: u16,             // Target share of new bond purchases
+    pub bond_amount: u64,            // Bond tokens currently held for this constituent
+    pub payment_feed_type: u8,       // The bond's own Etherfuse payment feed, stored like SovereignCoin::payment_feed_type
+}
+
+impl BasketConstituent {
+    pub fn get_payment_feed_type(&self) -> Result<PaymentFeedType> {
+        SovereignCoin::u8_to_payment_feed_type(self.payment_feed_type)
+    }
+}
+
+/// Set of Stablebonds backing a basket sovereign coin
+#[account]
+#[derive(InitSpace)]
+pub struct BondBasket {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub constituent_count: u8,
+    pub constituents: [BasketConstituent; MAX_BOND_MAPPINGS],
+}
+
+impl BondBasket {
+    pub fn constituents(&self) -> &[BasketConstituent] {
+        &self.constituents[..self.constituent_count as usize]
+    }
+
+    /// Split a bond purchase across the constituents by target weight.
+    /// The last constituent takes the rounding remainder so nothing is left unallocated.
+    pub fn split_by_weight(&self, amount: u64) -> Result<[u64; MAX_BOND_MAPPINGS]> {
+        let mut allocations = [0u64; MAX_BOND_MAPPINGS];
+        let count = self.constituent_count as usize;
+        let mut allocated = 0u64;
+
+        for (i, constituent) in self.constituents().iter().enumerate() {
+            allocations[i] = if i + 1 == count {
+                amount.safe_sub(allocated)?
+            } else {
+                calculate_percentage(amount, constituent.weight_bps, Rounding::Down)?
+            };
+            allocated = allocated.safe_add(allocations[i])?;
+        }
+
+        Ok(allocations)
+    }
+
+    /// Bond tokens to sell from each constituent to raise `amount` USDC out of a bond book worth
+    /// `book_value` USDC. Every holding gives up the same share, so the basket keeps its mix.
+    pub fn split_by_holdings(&self, amount: u64, book_value: u64) -> Result<[u64; MAX_BOND_MAPPINGS]> {
+        let mut allocations = [0u64; MAX_BOND_MAPPINGS];
+        require!(book_value >= amount, StablecoinError::InsufficientBondBalance);
+        if amount == 0 {
+            return Ok(allocations);
+        }
+
+        for (i, constituent) in self.constituents().iter().enumerate() {
+            allocations[i] = mul_div(constituent.bond_amount, amount, book_value, Rounding::Down)?;
+        }
+
+        Ok(allocations)
+    }
+
+    /// Pairs every constituent with its (bond mint, bond holding) accounts, passed
+    /// in constituent order through the remaining accounts
+    pub fn constituent_accounts<'a, 'info>(
+        &self,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Vec<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>> {
+        let count = self.constituent_count as usize;
+        require!(
+            remaining_accounts.len() >= count * 2,
+            StablecoinError::InvalidBasketAccounts
+        );
+
+        self.constituents()
+            .iter()
+            .zip(remaining_accounts.chunks(2))
+            .map(|(constituent, accounts)| {
+                let (bond_mint, bond_holding) = (&accounts[0], &accounts[1]);
+                require_keys_eq!(bond_mint.key(), constituent.bond_mint, StablecoinError::InvalidBondMint);
+                require_keys_eq!(bond_holding.key(), constituent.bond_holding, StablecoinError::InvalidBondHolding);
+                Ok((bond_mint, bond_holding))
+            })
+            .collect()
+    }
+
+    /// Weighted interest rate of the basket, each bond weighted by what is held of it
+    pub fn weighted_interest_rate(
+        &self,
+        remaining_accounts: &[AccountInfo],
+        curve: &EtherfuseFeeCurve,
+        tax_withholding_bps: u16,
+    ) -> Result<i16> {
+        let mut bond_rates = [0i16; MAX_BOND_MAPPINGS];
+        let mut bond_amounts = [0u64; MAX_BOND_MAPPINGS];
+
+        for (i, (bond_mint, _)) in self.constituent_accounts(remaining_accounts)?.into_iter().enumerate() {
+            bond_rates[i] = token_extension::read_current_interest_rate(bond_mint)?;
+            bond_amounts[i] = self.constituents[i].bond_amount;
+        }
+
+        interest::calculate_weighted_interest_rate(
+            bond_rates,
+            bond_amounts,
+            self.constituent_count as usize,
+            curve,
+            tax_withholding_bps,
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use super::*;
+
+/// Aggregate exposure of all sovereign coins backed by the same fiat currency
+#[account]
+#[derive(InitSpace)]
+pub struct CurrencyStats {
+    pub bump: u8,
+    pub fiat_currency: [u8; 8],        // Currency code (e.g., "USD", "MXN")
+    pub sovereign_coin_count: u32,     // Coins targeting this currency that have not been closed
+    pub total_supply: u128,            // Combined supply of those coins
+    pub usdc_reserve: u64,             // USDC held in the global reserve on their behalf
+    pub bond_exposure: u64,            // Bonds held on behalf of their holders
+    pub cumulative_protocol_fees: u64, // USDC mint and redeem fees collected over all time
+    pub last_updated: i64,
+}
+
+impl CurrencyStats {
+    /// USDC value backing the currency's coins, reserve plus bonds at book value
+    pub fn backing_value(&self) -> Result<u128> {
+        (self.usdc_reserve as u128).safe_add(self.bond_exposure as u128)
+    }
+
+    /// Count a coin into its currency and the factory totals the first time it meets the stats.
+    /// Coins created before the stats existed are seeded from the supply and backing they hold now,
+    /// so later redemptions never take out more than was counted in.
+    pub fn track_coin(
+        &mut self,
+        bump: u8,
+        sovereign_coin: &mut SovereignCoin,
+        factory: &mut Factory,
+        timestamp: i64,
+    ) -> Result<bool> {
+        if sovereign_coin.currency_stats_tracked {
+            return Ok(false);
+        }
+
+        self.bump = bump;
+        self.fiat_currency = sovereign_coin.target_fiat_currency;
+        self.sovereign_coin_count = self.sovereign_coin_count.safe_add(1)?;
+        self.record_mint(
+            sovereign_coin.total_supply,
+            sovereign_coin.usdc_amount,
+            sovereign_coin.bond_amount,
+            0,
+            timestamp,
+        )?;
+
+        factory.increase_total_supply(sovereign_coin.total_supply)?;
+        factory.increase_total_backing(sovereign_coin.usdc_amount.safe_add(sovereign_coin.bond_amount)?)?;
+        sovereign_coin.currency_stats_tracked = true;
+
+        Ok(true)
+    }
+
+    pub fn record_mint(
+        &mut self,
+        sovereign_amount: u64,
+        reserve_amount: u64,
+        bond_amount: u64,
+        protocol_fee: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.total_supply = self.total_supply.safe_add(sovereign_amount as u128)?;
+        self.usdc_reserve = self.usdc_reserve.safe_add(reserve_amount)?;
+        self.bond_exposure = self.bond_exposure.safe_add(bond_amount)?;
+        self.cumulative_protocol_fees = self.cumulative_protocol_fees.safe_add(protocol_fee)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    pub fn record_redeem(
+        &mut self,
+        sovereign_amount: u64,
+        from_usdc_reserve: u64,
+        from_bond_redemption: u64,
+        protocol_fee: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.total_supply = self.total_supply.safe_sub(sovereign_amount as u128)?;
+        self.usdc_reserve = self.usdc_reserve.safe_sub(from_usdc_reserve)?;
+        self.bond_exposure = self.bond_exposure.safe_sub(from_bond_redemption)?;
+        self.cumulative_protocol_fees = self.cumulative_protocol_fees.safe_add(protocol_fee)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    /// Bonds were turned into USDC that stays in the reserve
+    pub fn record_settlement(
+        &mut self,
+        bonds_redeemed: u64,
+        usdc_received: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.bond_exposure = self.bond_exposure.safe_sub(bonds_redeemed)?;
+        self.usdc_reserve = self.usdc_reserve.safe_add(usdc_received)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    /// A coin was closed, its remaining supply and settlement USDC leave the currency totals
+    pub fn record_close(&mut self, retired_supply: u64, unclaimed_usdc: u64, timestamp: i64) -> Result<()> {
+        self.sovereign_coin_count = self.sovereign_coin_count.safe_sub(1)?;
+        self.total_supply = self.total_supply.safe_sub(retired_supply as u128)?;
+        self.usdc_reserve = self.usdc_reserve.safe_sub(unclaimed_usdc)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    pub fn record_bridge_out(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.total_supply = self.total_supply.safe_sub(amount as u128)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+
+    pub fn record_bridge_in(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.total_supply = self.total_supply.safe_add(amount as u128)?;
+        self.last_updated = timestamp;
+        Ok(())
+    }
+}
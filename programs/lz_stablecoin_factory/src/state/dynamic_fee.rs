@@ -0,0 +1,61 @@
+use super::*;
+
+/// One breakpoint of the fee curve. Health is the coin's live USDC reserve ratio
+/// as a share of its required reserve, 10000 meaning exactly on target.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct DynamicFeePoint {
+    pub reserve_health_bps: u16,
+    pub mint_fee_bps: u16,
+    pub redeem_fee_bps: u16,
+}
+
+/// Mint and redeem fees that follow reserve health, interpolated linearly between breakpoints
+#[account]
+#[derive(InitSpace)]
+pub struct DynamicFeeConfig {
+    pub bump: u8,
+    pub enabled: bool,         // Flat factory.transfer_fee_bps applies while disabled
+    pub point_count: u8,
+    pub points: [DynamicFeePoint; MAX_DYNAMIC_FEE_POINTS],
+    pub updated_at: i64,
+}
+
+impl DynamicFeeConfig {
+    pub fn points(&self) -> &[DynamicFeePoint] {
+        &self.points[..self.point_count as usize]
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let points = self.points();
+        require!(
+            !self.enabled || !points.is_empty(),
+            StablecoinError::InvalidDynamicFeeCurve
+        );
+        require!(
+            points.iter().all(|point| point.mint_fee_bps < BASIS_POINT_MAX && point.redeem_fee_bps < BASIS_POINT_MAX),
+            StablecoinError::InvalidDynamicFeeCurve
+        );
+        require!(
+            points.windows(2).all(|pair| pair[0].reserve_health_bps < pair[1].reserve_health_bps),
+            StablecoinError::InvalidDynamicFeeCurve
+        );
+
+        Ok(())
+    }
+
+    /// Mint fee at the given reserve health, or the flat fee while the curve is off
+    pub fn mint_fee_bps(&self, reserve_health_bps: u16, flat_fee_bps: u16) -> Result<u16> {
+        if !self.enabled {
+            return Ok(flat_fee_bps);
+        }
+        fee::interpolate_fee_bps(self.points(), reserve_health_bps, |point| point.mint_fee_bps)
+    }
+
+    /// Redeem fee at the given reserve health, or the flat fee while the curve is off
+    pub fn redeem_fee_bps(&self, reserve_health_bps: u16, flat_fee_bps: u16) -> Result<u16> {
+        if !self.enabled {
+            return Ok(flat_fee_bps);
+        }
+        fee::interpolate_fee_bps(self.points(), reserve_health_bps, |point| point.redeem_fee_bps)
+    }
+}
@@ -34,4 +34,87 @@ pub struct Factory {
     pub bond_mappings: [BondCurrencyMapping; MAX_BOND_MAPPINGS],
     pub payment_base_price_feed_account: Pubkey,      // USDC/USD price feed
     pub payment_quote_price_feed_account: Option<Pubkey>,  // Optional quote price feed
+    pub total_backing_value: u128,    // USDC backing all coins, reserve plus bonds at book value
+}
+
+/// Factory as it was laid out before `total_backing_value`, only read by `migrate_factory`
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacyFactory {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub total_sovereign_coins: u64,
+    pub total_supply_all_coins: u128,
+    pub bond_rating_ordinals: [u8; 10],
+    pub global_usdc_reserve: Pubkey,
+    pub global_usdc_account: Pubkey,
+    pub min_usdc_reserve_percentage: u16,
+    pub bond_reserve_numerator: u8,
+    pub bond_reserve_denominator: u8,
+    pub yield_share_protocol: u16,
+    pub yield_share_issuer: u16,
+    pub yield_share_holders: u16,
+    pub transfer_fee_bps: u16,
+    pub maximum_transfer_fee: u64,
+    pub protocol_vault: Pubkey,
+    pub bond_mappings_count: u8,
+    pub bond_mappings: [BondCurrencyMapping; MAX_BOND_MAPPINGS],
+    pub payment_base_price_feed_account: Pubkey,
+    pub payment_quote_price_feed_account: Option<Pubkey>,
+}
+
+impl LegacyFactory {
+    /// The factory in the current layout. The backing total starts empty and each coin's
+    /// backing is counted into it as migrate_sovereign_coin brings the coin over.
+    pub fn into_current(self) -> Factory {
+        Factory {
+            bump: self.bump,
+            authority: self.authority,
+            treasury: self.treasury,
+            total_sovereign_coins: self.total_sovereign_coins,
+            total_supply_all_coins: self.total_supply_all_coins,
+            bond_rating_ordinals: self.bond_rating_ordinals,
+            global_usdc_reserve: self.global_usdc_reserve,
+            global_usdc_account: self.global_usdc_account,
+            min_usdc_reserve_percentage: self.min_usdc_reserve_percentage,
+            bond_reserve_numerator: self.bond_reserve_numerator,
+            bond_reserve_denominator: self.bond_reserve_denominator,
+            yield_share_protocol: self.yield_share_protocol,
+            yield_share_issuer: self.yield_share_issuer,
+            yield_share_holders: self.yield_share_holders,
+            transfer_fee_bps: self.transfer_fee_bps,
+            maximum_transfer_fee: self.maximum_transfer_fee,
+            protocol_vault: self.protocol_vault,
+            bond_mappings_count: self.bond_mappings_count,
+            bond_mappings: self.bond_mappings,
+            payment_base_price_feed_account: self.payment_base_price_feed_account,
+            payment_quote_price_feed_account: self.payment_quote_price_feed_account,
+            total_backing_value: 0,
+        }
+    }
+}
+
+impl Factory {
+    /// Keep the combined supply in step with every mint, burn and bridge of a sovereign coin
+    pub fn increase_total_supply(&mut self, amount: u64) -> Result<()> {
+        self.total_supply_all_coins = self.total_supply_all_coins.safe_add(amount as u128)?;
+        Ok(())
+    }
+
+    pub fn decrease_total_supply(&mut self, amount: u64) -> Result<()> {
+        self.total_supply_all_coins = self.total_supply_all_coins.safe_sub(amount as u128)?;
+        Ok(())
+    }
+
+    /// Keep the combined backing in step with every change to a currency's reserve or bond exposure
+    pub fn increase_total_backing(&mut self, amount: u64) -> Result<()> {
+        self.total_backing_value = self.total_backing_value.safe_add(amount as u128)?;
+        Ok(())
+    }
+
+    /// Saturates for backing booked before the total was tracked
+    pub fn decrease_total_backing(&mut self, amount: u64) -> Result<()> {
+        self.total_backing_value = self.total_backing_value.saturating_sub(amount as u128);
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+use super::*;
+
+/// Etherfuse management fee as a function of bond yield, all values in basis points except
+/// the slope and intercept which use 4 decimal fixed point (1.0000 = 10000)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct EtherfuseFeeCurve {
+    pub low_yield_threshold_bps: u16,   // Below this yield the flat low fee applies
+    pub high_yield_threshold_bps: u16,  // At or above this yield the flat high fee applies
+    pub low_fee_bps: u16,
+    pub high_fee_bps: u16,
+    pub slope: u32,                     // Linear segment: fee = slope * yield - intercept
+    pub intercept: u32,
+}
+
+impl Default for EtherfuseFeeCurve {
+    /// Commercial terms the program launched with
+    fn default() -> Self {
+        Self {
+            low_yield_threshold_bps: 450,
+            high_yield_threshold_bps: 1000,
+            low_fee_bps: 25,
+            high_fee_bps: 150,
+            slope: 2273,
+            intercept: 7727,
+        }
+    }
+}
+
+impl EtherfuseFeeCurve {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.low_yield_threshold_bps < self.high_yield_threshold_bps
+                && self.high_yield_threshold_bps <= BASIS_POINT_MAX
+                && self.low_fee_bps <= BASIS_POINT_MAX
+                && self.high_fee_bps <= BASIS_POINT_MAX,
+            StablecoinError::InvalidFeeCurve
+        );
+
+        // The linear segment must stay within range at both ends, it is monotonic in between
+        for yield_bps in [self.low_yield_threshold_bps, self.high_yield_threshold_bps - 1] {
+            interest::calculate_etherfuse_fee(yield_bps, self)
+                .map_err(|_| error!(StablecoinError::InvalidFeeCurve))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct CurrencyTaxRate {
+    pub fiat_currency: [u8; 8],
+    pub tax_withholding_bps: u16,
+}
+
+/// Admin governed Etherfuse fee curve and tax withholding applied when deriving coin rates from bond rates
+#[account]
+#[derive(InitSpace)]
+pub struct FeeCurveConfig {
+    pub bump: u8,
+    pub curve: EtherfuseFeeCurve,
+    pub tax_withholding_bps: u16,          // Default withholding for currencies without an override
+    pub currency_tax_count: u8,
+    pub currency_taxes: [CurrencyTaxRate; MAX_BOND_MAPPINGS],
+    pub updated_at: i64,
+}
+
+impl FeeCurveConfig {
+    /// Withholding for the currency if it has its own rate, the default otherwise
+    pub fn tax_withholding_bps_for(&self, fiat_currency: &[u8; 8]) -> u16 {
+        self.currency_taxes[..self.currency_tax_count as usize]
+            .iter()
+            .find(|tax| &tax.fiat_currency == fiat_currency)
+            .map(|tax| tax.tax_withholding_bps)
+            .unwrap_or(self.tax_withholding_bps)
+    }
+
+    /// Set, replace or (with `None`) remove the withholding override for a currency
+    pub fn set_currency_tax(&mut self, fiat_currency: [u8; 8], tax_withholding_bps: Option<u16>) -> Result<()> {
+        let count = self.currency_tax_count as usize;
+        let position = self.currency_taxes[..count]
+            .iter()
+            .position(|tax| tax.fiat_currency == fiat_currency);
+
+        match (position, tax_withholding_bps) {
+            (Some(i), Some(bps)) => self.currency_taxes[i].tax_withholding_bps = bps,
+            (Some(i), None) => {
+                // Swap remove, order carries no meaning
+                self.currency_taxes[i] = self.currency_taxes[count - 1];
+                self.currency_taxes[count - 1] = CurrencyTaxRate::default();
+                self.currency_tax_count -= 1;
+            }
+            (None, Some(bps)) => {
+                require!(count < MAX_BOND_MAPPINGS, StablecoinError::MaxCurrencyTaxRatesReached);
+                self.currency_taxes[count] = CurrencyTaxRate { fiat_currency, tax_withholding_bps: bps };
+                self.currency_tax_count += 1;
+            }
+            (None, None) => return err!(StablecoinError::CurrencyTaxRateNotFound),
+        }
+
+        Ok(())
+    }
+}
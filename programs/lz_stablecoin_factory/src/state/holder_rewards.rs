@@ -0,0 +1,30 @@
+use super::*;
+
+/// A holder's deposit in a claimable coin's rewards vault and the rewards it has earned
+#[account]
+#[derive(InitSpace)]
+pub struct HolderRewards {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub holder: Pubkey,
+    pub deposited: u64,               // Coins held in the rewards vault for this holder
+    pub reward_index_snapshot: u128,  // Coin reward index at the last checkpoint
+    pub pending_rewards: u64,         // USDC earned and not yet claimed
+    pub total_claimed: u64,
+    pub last_claimed_at: i64,
+}
+
+impl HolderRewards {
+    /// Credit what the deposit earned since the last checkpoint, must run before `deposited` changes
+    pub fn checkpoint(&mut self, reward_index: u128) -> Result<()> {
+        let earned = (self.deposited as u128)
+            .safe_mul(reward_index.safe_sub(self.reward_index_snapshot)?)?
+            .safe_div(REWARD_INDEX_PRECISION)?;
+        let earned = u64::try_from(earned).map_err(|_| error!(StablecoinError::MathOverflow))?;
+
+        self.pending_rewards = self.pending_rewards.safe_add(earned)?;
+        self.reward_index_snapshot = reward_index;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+/// Per-coin limits on redeeming straight into the coin's bonds, with the running totals they are checked against
+#[account]
+#[derive(InitSpace)]
+pub struct InKindRedemptionConfig {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub is_enabled: bool,
+    pub min_sovereign_amount: u64,     // Smaller redemptions go through the normal waterfall
+    pub max_sovereign_amount: u64,     // Cap on a single redemption, 0 for none
+    pub period_duration: i64,          // Seconds in each capped period, 0 disables the period cap
+    pub period_limit: u64,             // Coins that can be redeemed in kind per period
+    pub period_start: i64,
+    pub period_redeemed: u64,
+    pub cumulative_redeemed: u64,
+    pub cumulative_bonds_paid_out: u64,
+    pub updated_at: i64,
+}
+
+impl InKindRedemptionConfig {
+    /// Check a redemption against the limits and count it towards the current period
+    pub fn record_redemption(&mut self, sovereign_amount: u64, bonds_paid_out: u64, unix_timestamp: i64) -> Result<()> {
+        require!(self.is_enabled, StablecoinError::InKindRedemptionDisabled);
+        require!(
+            sovereign_amount >= self.min_sovereign_amount
+                && (self.max_sovereign_amount == 0 || sovereign_amount <= self.max_sovereign_amount),
+            StablecoinError::InKindRedemptionLimitExceeded
+        );
+
+        if self.period_duration > 0 {
+            if unix_timestamp >= self.period_start.safe_add(self.period_duration)? {
+                self.period_start = unix_timestamp;
+                self.period_redeemed = 0;
+            }
+            self.period_redeemed = self.period_redeemed.safe_add(sovereign_amount)?;
+            require!(
+                self.period_redeemed <= self.period_limit,
+                StablecoinError::InKindRedemptionLimitExceeded
+            );
+        }
+
+        self.cumulative_redeemed = self.cumulative_redeemed.safe_add(sovereign_amount)?;
+        self.cumulative_bonds_paid_out = self.cumulative_bonds_paid_out.safe_add(bonds_paid_out)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct InterestRateHistoryEntry {
+    pub previous_rate: i16,  // Rate on the mint before the update
+    pub new_rate: i16,       // Rate written to the mint
+    pub target_rate: i16,    // Unsmoothed rate derived from the bond
+    pub updated_at: i64,
+    pub updater: Pubkey,     // Keeper that ran the crank
+}
+
+/// Keeper settings and audit trail for the permissionless interest rate crank
+#[account]
+#[derive(InitSpace)]
+pub struct InterestRateConfig {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub update_interval: i64,     // Minimum seconds between two cranks
+    pub max_rate_step_bps: u16,   // Largest move allowed in a single crank
+    pub rate_floor: i16,          // Rate never goes below this
+    pub rate_ceiling: i16,        // Rate never goes above this
+    pub last_update_at: i64,
+    pub history_head: u8,         // Next slot to write in the ring buffer
+    pub history_count: u8,
+    pub history: [InterestRateHistoryEntry; INTEREST_RATE_HISTORY_LEN],
+}
+
+impl InterestRateConfig {
+    pub fn can_update(&self, unix_timestamp: i64) -> Result<bool> {
+        Ok(unix_timestamp >= self.last_update_at.safe_add(self.update_interval)?)
+    }
+
+    pub fn record_update(&mut self, entry: InterestRateHistoryEntry) {
+        self.history[self.history_head as usize] = entry;
+        self.history_head = ((self.history_head as usize + 1) % INTEREST_RATE_HISTORY_LEN) as u8;
+        if (self.history_count as usize) < INTEREST_RATE_HISTORY_LEN {
+            self.history_count += 1;
+        }
+        self.last_update_at = entry.updated_at;
+    }
+}
@@ -24,14 +24,14 @@ pub struct OFTStore {
 }
 
 impl OFTStore {
-    // Convert local decimals to shared decimals
-    pub fn ld2sd(&self, amount_ld: u64) -> u64 {
-        amount_ld / self.ld2sd_rate
+    // Convert local decimals to shared decimals, ld2sd_rate comes from the coin's own decimals
+    pub fn ld2sd(&self, amount_ld: u64) -> Result<u64> {
+        amount_ld.checked_div(self.ld2sd_rate).ok_or_else(|| error!(StablecoinError::MathError))
     }
 
-    // Convert shared decimals to local decimals
-    pub fn sd2ld(&self, amount_sd: u64) -> u64 {
-        amount_sd * self.ld2sd_rate
+    // Convert shared decimals to local decimals, high decimal coins can overflow u64 here
+    pub fn sd2ld(&self, amount_sd: u64) -> Result<u64> {
+        amount_sd.checked_mul(self.ld2sd_rate).ok_or_else(|| error!(StablecoinError::MathError))
     }
 
     // Remove dust for precise calculations
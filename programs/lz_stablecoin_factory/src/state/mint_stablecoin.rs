@@ -12,6 +12,12 @@ pub struct MintSovereignState {
     pub reserve_amount: u64,
     pub bond_amount: u64,
     pub protocol_fee: u64,
+    pub spread_amount: u64,    // USDC kept for the spread recipient instead of backing the coin
+    pub spread_bps: u16,
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+    pub oracle_price_mantissa: i128, // Oracle price the trade was checked at, recorded in the price history on execution
+    pub oracle_price_scale: u32,
     pub created_at: i64,
     pub bump: u8,
 }
@@ -5,6 +5,19 @@ pub mod stablecoin;
 pub mod mint_stablecoin;
 pub mod redeem_stablecoin;
 pub mod fee_operator;
+pub mod currency_stats;
+pub mod sovereign_coin_stats;
+pub mod interest_rate_config;
+pub mod bond_basket;
+pub mod fee_curve;
+pub mod holder_rewards;
+pub mod reserve_policy;
+pub mod dynamic_fee;
+pub mod usdc_peg_guard;
+pub mod price_route;
+pub mod rfq_quote;
+pub mod price_history;
+pub mod in_kind_redemption;
 // pub mod compressed;
 
 pub use factory::*;
@@ -12,4 +25,17 @@ pub use stablecoin::*;
 pub use mint_stablecoin::*;
 pub use redeem_stablecoin::*;
 pub use fee_operator::*;
+pub use currency_stats::*;
+pub use sovereign_coin_stats::*;
+pub use interest_rate_config::*;
+pub use bond_basket::*;
+pub use fee_curve::*;
+pub use holder_rewards::*;
+pub use reserve_policy::*;
+pub use dynamic_fee::*;
+pub use usdc_peg_guard::*;
+pub use price_route::*;
+pub use rfq_quote::*;
+pub use price_history::*;
+pub use in_kind_redemption::*;
 // pub use compressed::*;
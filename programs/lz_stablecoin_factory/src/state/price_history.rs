@@ -0,0 +1,291 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum PriceSampleSource {
+    #[default]
+    Crank,
+    Mint,
+    Redeem,
+}
+
+/// Oracle price of the coin's currency per USDC at one point in time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PriceSample {
+    pub mantissa: i128,
+    pub scale: u32,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub source: PriceSampleSource,
+}
+
+impl PriceSample {
+    pub fn new((mantissa, scale): (i128, u32), source: PriceSampleSource, clock: &Clock) -> Self {
+        Self {
+            mantissa,
+            scale,
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+            source,
+        }
+    }
+
+    pub fn normalized_price(&self) -> Result<i128> {
+        Ok(switchboard::normalize_decimal(self.mantissa, self.scale)?.0)
+    }
+}
+
+/// Ring buffer of the oracle prices a coin traded at, with the TWAP guard applied to new trades
+#[account]
+#[derive(InitSpace)]
+pub struct PriceHistory {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub crank_interval: i64,          // Minimum seconds between two crank samples
+    pub twap_window: i64,             // Seconds the trade deviation check averages over
+    pub max_twap_deviation_bps: u16,  // Trades priced further than this from the TWAP are refused, 0 disables the check
+    pub last_crank_at: i64,
+    pub head: u8,                     // Next slot to write in the ring buffer
+    pub count: u8,
+    pub samples: [PriceSample; PRICE_HISTORY_LEN],
+}
+
+impl PriceHistory {
+    pub fn can_crank(&self, unix_timestamp: i64) -> Result<bool> {
+        Ok(unix_timestamp >= self.last_crank_at.safe_add(self.crank_interval)?)
+    }
+
+    pub fn record(&mut self, sample: PriceSample) {
+        self.samples[self.head as usize] = sample;
+        self.head = ((self.head as usize + 1) % PRICE_HISTORY_LEN) as u8;
+        if (self.count as usize) < PRICE_HISTORY_LEN {
+            self.count += 1;
+        }
+        if sample.source == PriceSampleSource::Crank {
+            self.last_crank_at = sample.timestamp;
+        }
+    }
+
+    /// Settings a coin starts with until an admin configures its history
+    pub fn initialize(&mut self, bump: u8, sovereign_coin: Pubkey) {
+        self.bump = bump;
+        self.sovereign_coin = sovereign_coin;
+        self.crank_interval = MIN_PRICE_HISTORY_CRANK_INTERVAL;
+        self.twap_window = DEFAULT_PRICE_HISTORY_TWAP_WINDOW;
+        self.max_twap_deviation_bps = 0;
+    }
+
+    /// Refuse a trade whose oracle price is further from the configured TWAP than allowed
+    pub fn check_trade_price(&self, price: (i128, u32), unix_timestamp: i64) -> Result<()> {
+        if self.max_twap_deviation_bps > 0 {
+            if let Some(twap) = self.twap(self.twap_window, unix_timestamp)? {
+                let (price, _) = switchboard::normalize_decimal(price.0, price.1)?;
+                require!(
+                    is_within_deviation(price, twap, self.max_twap_deviation_bps)?,
+                    StablecoinError::PriceDeviatesFromTwap
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keep the oracle price a trade settled at, once it has gone through
+    pub fn record_trade_price(&mut self, price: (i128, u32), source: PriceSampleSource, clock: &Clock) {
+        self.record(PriceSample::new(price, source, clock));
+    }
+
+    /// Recorded samples, newest first
+    pub fn samples(&self) -> impl Iterator<Item = &PriceSample> {
+        (1..=self.count as usize)
+            .map(move |back| &self.samples[(self.head as usize + PRICE_HISTORY_LEN - back) % PRICE_HISTORY_LEN])
+    }
+
+    pub fn latest(&self) -> Option<&PriceSample> {
+        self.samples().next()
+    }
+
+    /// Time-weighted average at ORACLE_PRICE_SCALE over the last `window` seconds. Each sample holds
+    /// until the next one, so a burst of samples in the same second carries no extra weight.
+    /// None when nothing has been recorded.
+    pub fn twap(&self, window: i64, unix_timestamp: i64) -> Result<Option<i128>> {
+        let window_start = unix_timestamp.safe_sub(window)?;
+        let mut weighted_sum: i128 = 0;
+        let mut total_weight: i64 = 0;
+        let mut held_until = unix_timestamp;
+
+        for sample in self.samples() {
+            let held_from = sample.timestamp.max(window_start);
+            if held_until > held_from {
+                let weight = held_until.safe_sub(held_from)?;
+                weighted_sum = weighted_sum.safe_add(sample.normalized_price()?.safe_mul(weight as i128)?)?;
+                total_weight = total_weight.safe_add(weight)?;
+            }
+            if sample.timestamp <= window_start {
+                break;
+            }
+            held_until = held_until.min(sample.timestamp);
+        }
+
+        if total_weight == 0 {
+            // Every sample landed this second, the latest one is the average
+            return self.latest().map(|sample| sample.normalized_price()).transpose();
+        }
+
+        Ok(Some(weighted_sum.safe_div(total_weight as i128)?))
+    }
+
+    /// Exponential average at ORACLE_PRICE_SCALE of the samples from the last `window` seconds,
+    /// oldest first with a smoothing factor of 2 / (samples + 1). None when the window is empty.
+    pub fn ema(&self, window: i64, unix_timestamp: i64) -> Result<Option<i128>> {
+        let in_window = self.count_in_window(window, unix_timestamp)? as usize;
+        if in_window == 0 {
+            return Ok(None);
+        }
+
+        let samples: Vec<&PriceSample> = self.samples().take(in_window).collect();
+        let mut ema = samples[in_window - 1].normalized_price()?;
+        for sample in samples.iter().rev().skip(1) {
+            let delta = sample.normalized_price()?.safe_sub(ema)?;
+            ema = ema.safe_add(delta.safe_mul(2)?.safe_div(in_window as i128 + 1)?)?;
+        }
+
+        Ok(Some(ema))
+    }
+
+    /// Samples recorded in the last `window` seconds
+    pub fn count_in_window(&self, window: i64, unix_timestamp: i64) -> Result<u8> {
+        let window_start = unix_timestamp.safe_sub(window)?;
+
+        Ok(self.samples().take_while(|sample| sample.timestamp >= window_start).count() as u8)
+    }
+}
+
+/// Whether `price` is within `max_deviation_bps` of `reference`, both at the same scale
+pub fn is_within_deviation(price: i128, reference: i128, max_deviation_bps: u16) -> Result<bool> {
+    let deviation = price.safe_sub(reference)?.unsigned_abs();
+    let max_deviation = reference.unsigned_abs()
+        .safe_mul(max_deviation_bps as u128)?
+        .safe_div(BASIS_POINT_MAX as u128)?;
+
+    Ok(deviation <= max_deviation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE: i128 = 1_000_000_000_000_000_000;
+
+    fn history(max_twap_deviation_bps: u16) -> PriceHistory {
+        PriceHistory {
+            bump: 0,
+            sovereign_coin: Pubkey::default(),
+            crank_interval: MIN_PRICE_HISTORY_CRANK_INTERVAL,
+            twap_window: DEFAULT_PRICE_HISTORY_TWAP_WINDOW,
+            max_twap_deviation_bps,
+            last_crank_at: 0,
+            head: 0,
+            count: 0,
+            samples: [PriceSample::default(); PRICE_HISTORY_LEN],
+        }
+    }
+
+    fn sample(mantissa: i128, scale: u32, timestamp: i64) -> PriceSample {
+        PriceSample {
+            mantissa,
+            scale,
+            slot: timestamp as u64,
+            timestamp,
+            source: PriceSampleSource::Crank,
+        }
+    }
+
+    #[test]
+    fn empty_history_has_no_averages_and_checks_nothing() {
+        let history = history(100);
+
+        assert!(history.latest().is_none());
+        assert_eq!(history.count_in_window(3600, 1_000).unwrap(), 0);
+        assert_eq!(history.twap(3600, 1_000).unwrap(), None);
+        assert_eq!(history.ema(3600, 1_000).unwrap(), None);
+        // Nothing to deviate from yet, the first trade goes through
+        assert!(history.check_trade_price((17, 0), 1_000).is_ok());
+    }
+
+    #[test]
+    fn ring_buffer_wraps_and_keeps_the_newest_samples() {
+        let mut history = history(0);
+        let recorded = PRICE_HISTORY_LEN + 3;
+        for i in 0..recorded {
+            history.record(sample(i as i128, 0, i as i64));
+        }
+
+        assert_eq!(history.count as usize, PRICE_HISTORY_LEN);
+        assert_eq!(history.head, 3);
+        assert_eq!(history.last_crank_at, recorded as i64 - 1);
+
+        // Newest first, the three oldest were overwritten
+        let timestamps: Vec<i64> = history.samples().map(|sample| sample.timestamp).collect();
+        assert_eq!(timestamps.len(), PRICE_HISTORY_LEN);
+        assert_eq!(timestamps[0], recorded as i64 - 1);
+        assert_eq!(*timestamps.last().unwrap(), 3);
+        assert!(timestamps.windows(2).all(|pair| pair[0] == pair[1] + 1));
+    }
+
+    #[test]
+    fn twap_weights_each_sample_by_how_long_it_held() {
+        let mut history = history(0);
+        history.record(sample(10, 0, 0));
+        history.record(sample(20, 0, 30));
+
+        // 10 held for 30s and 20 for 30s
+        assert_eq!(history.twap(60, 60).unwrap(), Some(15 * ONE));
+        // Only the last 15s fall in the window, all at 20
+        assert_eq!(history.twap(15, 60).unwrap(), Some(20 * ONE));
+        // A sample from the current second hasn't held for any time yet
+        assert_eq!(history.twap(60, 30).unwrap(), Some(10 * ONE));
+    }
+
+    #[test]
+    fn ema_smooths_oldest_to_newest() {
+        let mut history = history(0);
+        history.record(sample(10, 0, 0));
+        history.record(sample(20, 0, 30));
+
+        // 10 + (20 - 10) * 2 / 3
+        assert_eq!(history.ema(60, 60).unwrap(), Some(16_666_666_666_666_666_666));
+        // The older sample falls out of the window
+        assert_eq!(history.ema(30, 60).unwrap(), Some(20 * ONE));
+    }
+
+    #[test]
+    fn trade_price_deviation_is_inclusive_at_the_bound() {
+        let mut history = history(100);
+        history.record(sample(100, 0, 0));
+
+        // Exactly 1% either side of a TWAP of 100 is allowed
+        assert!(history.check_trade_price((101, 0), 10).is_ok());
+        assert!(history.check_trade_price((99, 0), 10).is_ok());
+        // One unit past it at the oracle scale is not
+        assert!(history.check_trade_price((101 * ONE + 1, ORACLE_PRICE_SCALE), 10).is_err());
+        assert!(history.check_trade_price((99 * ONE - 1, ORACLE_PRICE_SCALE), 10).is_err());
+
+        // A disabled check lets anything through
+        history.max_twap_deviation_bps = 0;
+        assert!(history.check_trade_price((1_000, 0), 10).is_ok());
+    }
+
+    #[test]
+    fn crank_interval_counts_from_the_last_crank_only() {
+        let mut history = history(0);
+        history.record(sample(10, 0, 1_000));
+        assert!(!history.can_crank(1_000 + MIN_PRICE_HISTORY_CRANK_INTERVAL - 1).unwrap());
+        assert!(history.can_crank(1_000 + MIN_PRICE_HISTORY_CRANK_INTERVAL).unwrap());
+
+        // Trade samples don't push the next crank back
+        let mut trade = sample(11, 0, 1_030);
+        trade.source = PriceSampleSource::Mint;
+        history.record(trade);
+        assert_eq!(history.last_crank_at, 1_000);
+    }
+}
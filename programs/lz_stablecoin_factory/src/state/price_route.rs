@@ -0,0 +1,83 @@
+use super::*;
+
+/// One oracle feed in a price route. The feed quotes `quote_currency` per `base_currency`;
+/// an inverted leg is walked the other way, from quote to base, using 1 / price.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct PriceFeedLeg {
+    pub feed: Pubkey,
+    pub base_currency: [u8; 8],
+    pub quote_currency: [u8; 8],
+    pub invert: bool,
+}
+
+impl PriceFeedLeg {
+    /// Currencies this leg converts between once the inversion is applied
+    pub fn direction(&self) -> ([u8; 8], [u8; 8]) {
+        if self.invert {
+            (self.quote_currency, self.base_currency)
+        } else {
+            (self.base_currency, self.quote_currency)
+        }
+    }
+}
+
+/// Chain of feeds pricing a fiat currency in USDC, e.g. USDC→USD→EUR→CHF
+#[account]
+#[derive(InitSpace)]
+pub struct PriceRoute {
+    pub bump: u8,
+    pub fiat_currency: [u8; 8],
+    pub leg_count: u8,
+    pub legs: [PriceFeedLeg; MAX_PRICE_ROUTE_LEGS],
+    pub updated_at: i64,
+}
+
+impl PriceRoute {
+    pub fn legs(&self) -> &[PriceFeedLeg] {
+        &self.legs[..self.leg_count as usize]
+    }
+
+    /// The route must start at USDC, end at the route's currency and have every leg pick up where the last one left off
+    pub fn validate(&self) -> Result<()> {
+        let legs = self.legs();
+        require!(!legs.is_empty(), StablecoinError::InvalidPriceRoute);
+
+        let mut currency = currency_code("USDC");
+        for leg in legs {
+            let (from, to) = leg.direction();
+            require!(from == currency, StablecoinError::InvalidPriceRoute);
+            currency = to;
+        }
+        require!(currency == self.fiat_currency, StablecoinError::InvalidPriceRoute);
+
+        Ok(())
+    }
+
+    /// Route stored at a currency's price route address, None when no route has been configured for it
+    pub fn load_configured(account: &AccountInfo) -> Result<Option<PriceRoute>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = account.try_borrow_data()?;
+        Ok(Some(PriceRoute::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Price of the route's currency per USDC, from the leg feeds passed in route order
+    pub fn resolve_price(&self, feed_accounts: &[AccountInfo]) -> Result<(i128, u32)> {
+        require!(feed_accounts.len() >= self.legs().len(), StablecoinError::PriceRouteFeedMismatch);
+        for (leg, feed_account) in self.legs().iter().zip(feed_accounts) {
+            require_keys_eq!(feed_account.key(), leg.feed, StablecoinError::PriceRouteFeedMismatch);
+        }
+
+        switchboard::calculate_route_price(self.legs(), feed_accounts)
+    }
+}
+
+/// Zero padded currency code as stored on chain
+pub fn currency_code(code: &str) -> [u8; 8] {
+    let mut bytes = [0u8; 8];
+    let len = code.len().min(8);
+    bytes[..len].copy_from_slice(&code.as_bytes()[..len]);
+    bytes
+}
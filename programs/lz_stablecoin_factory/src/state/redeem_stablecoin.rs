@@ -13,6 +13,12 @@ pub struct RedeemSovereignState {
     pub from_protocol_vault: u64,
     pub from_bond_redemption: u64,
     pub protocol_fee: u64,
+    pub spread_amount: u64,    // USDC withheld from the payout for the spread recipient
+    pub spread_bps: u16,
+    pub oracle_rate: u64,
+    pub applied_rate: u64,
+    pub oracle_price_mantissa: i128, // Oracle price the trade was checked at, recorded in the price history on execution
+    pub oracle_price_scale: u32,
     pub redemption_type: RedemptionTypeState,
     pub created_at: i64,
     pub bump: u8,
@@ -0,0 +1,46 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ReserveCurveKind {
+    Linear,     // base + (rating - 1) * numerator / denominator from the factory
+    StepTable,  // Fixed reserve per rating from rating_steps_bps
+    Convex,     // base + (rating - 1)^2 * convex_coefficient_bps
+}
+
+/// Risk governed reserve requirement consulted by the mint split and reserve refreshes
+#[account]
+#[derive(InitSpace)]
+pub struct ReservePolicy {
+    pub bump: u8,
+    pub curve_kind: ReserveCurveKind,
+    pub rating_steps_bps: [u16; 10],          // Reserve for ratings 1 to 10, used by StepTable
+    pub convex_coefficient_bps: u16,          // Used by Convex
+    pub floor_bps: u16,                       // Requirement never goes below this
+    pub cap_bps: u16,                         // Requirement never goes above this
+    pub concentration_threshold_bps: u16,     // Currency share of all backing value from which the surcharge applies
+    pub concentration_surcharge_bps: u16,     // Extra reserve for coins of an over-concentrated currency
+    pub min_mint_collateral_ratio_bps: u16,   // Mints stop below this marked collateral ratio, 0 disables
+    pub updated_at: i64,
+}
+
+impl ReservePolicy {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.floor_bps <= self.cap_bps && self.cap_bps <= BASIS_POINT_MAX,
+            StablecoinError::InvalidReservePolicy
+        );
+        require!(
+            self.concentration_threshold_bps <= BASIS_POINT_MAX
+                && self.concentration_surcharge_bps <= BASIS_POINT_MAX,
+            StablecoinError::InvalidReservePolicy
+        );
+        if self.curve_kind == ReserveCurveKind::StepTable {
+            require!(
+                self.rating_steps_bps.iter().all(|&step| step <= BASIS_POINT_MAX),
+                StablecoinError::InvalidReservePolicy
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RfqSide {
+    Mint,   // Amount is the USDC paid in
+    Redeem, // Amount is the raw coins redeemed
+}
+
+/// Firm price an issuer offers one taker for one trade. The quote signer signs the
+/// Borsh encoding of this struct with an ed25519 instruction placed right before the trade.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RfqQuote {
+    pub sovereign_coin: Pubkey,
+    pub taker: Pubkey,      // Only this payer can fill the quote
+    pub side: RfqSide,
+    pub amount: u64,
+    pub rate: u64,          // Target currency per USDC, scaled by PRICE_PRECISION
+    pub expiry: i64,        // Last unix timestamp the quote can be filled at
+    pub nonce: u64,         // Unique per coin, a filled nonce can't be filled again
+}
+
+impl RfqQuote {
+    /// The rate as an oracle price, so it can stand in for the feed price in a mint or redeem quote
+    pub fn payment_price(&self) -> Result<(i128, u32)> {
+        require!(self.rate > 0, StablecoinError::InvalidRfqQuote);
+
+        Ok((self.rate as i128, PRICE_PRECISION_DECIMALS as u32))
+    }
+}
+
+/// Key an issuer quotes firm prices for a coin with
+#[account]
+#[derive(InitSpace)]
+pub struct RfqQuoteSigner {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub signer: Pubkey,
+    pub is_enabled: bool,
+    pub max_oracle_deviation_bps: u16, // Quotes further than this from the oracle price or TWAP are refused
+    pub updated_at: i64,
+}
+
+/// Marks a quote nonce as filled, the account can only be created once per coin and nonce
+#[account]
+#[derive(InitSpace)]
+pub struct RfqNonce {
+    pub sovereign_coin: Pubkey,
+    pub nonce: u64,
+    pub taker: Pubkey,
+    pub filled_at: i64,
+}
+
+impl RfqNonce {
+    pub fn record_fill(&mut self, quote: &RfqQuote, unix_timestamp: i64) {
+        self.sovereign_coin = quote.sovereign_coin;
+        self.nonce = quote.nonce;
+        self.taker = quote.taker;
+        self.filled_at = unix_timestamp;
+    }
+}
@@ -0,0 +1,167 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, InitSpace)]
+pub struct PeerBridgeStats {
+    pub eid: u32,          // LayerZero endpoint id of the peer chain
+    pub bridged_out: u64,  // Amount sent to this peer
+    pub bridged_in: u64,   // Amount received from this peer
+}
+
+/// Lifetime counters for a single sovereign coin, kept on-chain so analytics
+/// do not depend on replaying event logs
+#[account]
+#[derive(InitSpace)]
+pub struct SovereignCoinStats {
+    pub bump: u8,
+    pub sovereign_coin: Pubkey,
+    pub cumulative_minted: u64,
+    pub cumulative_usdc_deposited: u64,
+    // Cumulative sovereign coins redeemed, split by RedemptionTypeState
+    pub redeemed_usdc_reserve_only: u64,
+    pub redeemed_usdc_reserve_and_protocol: u64,
+    pub redeemed_instant_bond: u64,
+    pub redeemed_nft_bond: u64,
+    pub redeemed_settlement: u64,       // Pro-rata claims after the coin was settled
+    pub cumulative_usdc_paid_out: u64,
+    pub protocol_fees_collected: u64,   // USDC fees charged on mint and redeem
+    pub bridge_fees_collected: u64,     // Sovereign coin fees charged on LayerZero sends
+    pub transfer_fees_harvested: u64,   // Withheld transfer fees moved into the protocol vault
+    pub fees_withdrawn: u64,            // Sovereign coin fees withdrawn from the protocol vault
+    pub total_bridged_out: u64,
+    pub total_bridged_in: u64,
+    pub peer_count: u8,
+    pub peers: [PeerBridgeStats; MAX_TRACKED_PEERS],
+    pub last_mint_at: i64,
+    pub last_redeem_at: i64,
+    pub last_bridge_at: i64,
+    pub last_fee_at: i64,
+    pub last_activity_at: i64,
+}
+
+impl SovereignCoinStats {
+    pub fn record_mint(
+        &mut self,
+        sovereign_amount: u64,
+        usdc_amount: u64,
+        protocol_fee: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.cumulative_minted = self.cumulative_minted.safe_add(sovereign_amount)?;
+        self.cumulative_usdc_deposited = self.cumulative_usdc_deposited.safe_add(usdc_amount)?;
+        self.protocol_fees_collected = self.protocol_fees_collected.safe_add(protocol_fee)?;
+        self.last_mint_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    pub fn record_redeem(
+        &mut self,
+        redemption_type: RedemptionTypeState,
+        sovereign_amount: u64,
+        usdc_paid_out: u64,
+        protocol_fee: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        let redeemed = match redemption_type {
+            RedemptionTypeState::UsdcReserveOnly => &mut self.redeemed_usdc_reserve_only,
+            RedemptionTypeState::UsdcReserveAndProtocol => &mut self.redeemed_usdc_reserve_and_protocol,
+            RedemptionTypeState::InstantBondRedemption => &mut self.redeemed_instant_bond,
+            RedemptionTypeState::NFTBondRedemption => &mut self.redeemed_nft_bond,
+        };
+        *redeemed = redeemed.safe_add(sovereign_amount)?;
+
+        self.cumulative_usdc_paid_out = self.cumulative_usdc_paid_out.safe_add(usdc_paid_out)?;
+        self.protocol_fees_collected = self.protocol_fees_collected.safe_add(protocol_fee)?;
+        self.last_redeem_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    /// In-kind redemptions are counted on their InKindRedemptionConfig, only the USDC leg is tracked here
+    pub fn record_in_kind_redeem(&mut self, usdc_paid_out: u64, timestamp: i64) -> Result<()> {
+        self.cumulative_usdc_paid_out = self.cumulative_usdc_paid_out.safe_add(usdc_paid_out)?;
+        self.last_redeem_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    pub fn record_settlement_claim(
+        &mut self,
+        sovereign_amount: u64,
+        usdc_paid_out: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.redeemed_settlement = self.redeemed_settlement.safe_add(sovereign_amount)?;
+        self.cumulative_usdc_paid_out = self.cumulative_usdc_paid_out.safe_add(usdc_paid_out)?;
+        self.last_redeem_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    /// Returns false when the peer has no counters of its own because every slot is taken
+    pub fn record_bridge_out(
+        &mut self,
+        dst_eid: u32,
+        amount: u64,
+        bridge_fee: u64,
+        timestamp: i64,
+    ) -> Result<bool> {
+        self.total_bridged_out = self.total_bridged_out.safe_add(amount)?;
+        self.bridge_fees_collected = self.bridge_fees_collected.safe_add(bridge_fee)?;
+        let peer_tracked = match self.peer_stats_mut(dst_eid) {
+            Some(peer) => {
+                peer.bridged_out = peer.bridged_out.safe_add(amount)?;
+                true
+            }
+            None => false,
+        };
+        self.last_bridge_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(peer_tracked)
+    }
+
+    /// Returns false when the peer has no counters of its own because every slot is taken
+    pub fn record_bridge_in(&mut self, src_eid: u32, amount: u64, timestamp: i64) -> Result<bool> {
+        self.total_bridged_in = self.total_bridged_in.safe_add(amount)?;
+        let peer_tracked = match self.peer_stats_mut(src_eid) {
+            Some(peer) => {
+                peer.bridged_in = peer.bridged_in.safe_add(amount)?;
+                true
+            }
+            None => false,
+        };
+        self.last_bridge_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(peer_tracked)
+    }
+
+    pub fn record_transfer_fees_harvested(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.transfer_fees_harvested = self.transfer_fees_harvested.safe_add(amount)?;
+        self.last_fee_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    pub fn record_fees_withdrawn(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        self.fees_withdrawn = self.fees_withdrawn.safe_add(amount)?;
+        self.last_fee_at = timestamp;
+        self.last_activity_at = timestamp;
+        Ok(())
+    }
+
+    /// Finds the counters for a peer, claiming a free slot on first use.
+    /// Once all MAX_TRACKED_PEERS slots are taken, new peers only show up in the totals
+    /// and every bridge with them emits a PeerStatsUntrackedEvent.
+    fn peer_stats_mut(&mut self, eid: u32) -> Option<&mut PeerBridgeStats> {
+        let count = self.peer_count as usize;
+        if let Some(index) = self.peers[..count].iter().position(|peer| peer.eid == eid) {
+            return Some(&mut self.peers[index]);
+        }
+        if count < MAX_TRACKED_PEERS {
+            self.peers[count].eid = eid;
+            self.peer_count += 1;
+            return Some(&mut self.peers[count]);
+        }
+        None
+    }
+}
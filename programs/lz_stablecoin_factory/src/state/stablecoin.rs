@@ -30,32 +30,294 @@ pub struct SovereignCoin {
     pub oft_store: Option<Pubkey>,
     pub is_cross_chain_enabled: bool,
     pub cross_chain_admin: Option<Pubkey>,
+    pub uses_token_metadata: bool, // Metadata lives in the Token-2022 mint instead of a Metaplex account
+    pub lifecycle: SovereignCoinLifecycle, // Wind-down stage of the coin
+    pub settled_at: i64,       // When the remaining bonds were redeemed for pro-rata claims
+    pub is_basket: bool,       // Backed by a BondBasket of several Stablebonds instead of bond_mint alone
+    pub net_yield_rate: i16,   // Bond rate after Etherfuse fee and tax, before the holders' share is applied
+    pub protocol_yield_accrued: u64, // Protocol's share of bond yield owed in USDC, not yet settled
+    pub issuer_yield_accrued: u64,   // Issuer's share of bond yield owed in USDC, not yet settled
+    pub yield_accrued_at: i64,
+    pub yield_mode: YieldMode, // How the holders' share of yield reaches them
+    pub reward_payout: RewardPayout, // What claimable rewards are paid in
+    pub reward_index: u128,    // Cumulative USDC rewards per deposited coin, scaled by REWARD_INDEX_PRECISION
+    pub reward_deposits: u64,  // Coins deposited in the rewards vault and earning claimable rewards
+    pub holder_yield_accrued: u64, // Holders' claimable share of yield owed in USDC, not yet in the reserve
+    pub reward_usdc_balance: u64,  // USDC set aside in the reserve for rewards not yet claimed
+    pub marked_bond_value: u64,    // Bond holdings at their last mark to market, in USDC
+    pub collateral_ratio_bps: u64, // Reserve plus marked bonds over outstanding supply at the last mark
+    pub collateral_marked_at: i64,
+    pub mint_spread_bps: u16,      // Taken off the oracle rate when minting, hedges the issuer's FX exposure
+    pub redeem_spread_bps: u16,    // Taken off the oracle rate when redeeming
+    pub spread_recipient: SpreadRecipient,
+    pub spread_revenue_accrued: u64, // Spread USDC held in the reserve for the recipient, not backing the coin
+    pub currency_stats_tracked: bool, // Counted in its CurrencyStats and the factory totals
+}
+
+/// SovereignCoin as it was laid out before the lifecycle, yield, reward, collateral and spread fields.
+/// Only read by `migrate_sovereign_coin` to carry coins created before the upgrade over to the current layout.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct LegacySovereignCoin {
+    pub bump: u8,
+    pub creator: Pubkey,
+    pub factory: Pubkey,
+    pub name: [u8; 32],
+    pub symbol: [u8; 8],
+    pub decimals: u8,
+    #[max_len(200)]
+    pub uri: Vec<u8>,
+    pub target_fiat_currency: [u8; 8],
+    pub bond_mint: Pubkey,
+    pub bond_account: Pubkey,
+    pub mint: Pubkey,
+    pub bond_holding: Pubkey,
+    pub bond_ownership: Pubkey,
+    pub total_supply: u64,
+    pub bond_rating: u8,
+    pub required_reserve_percentage: u16,
+    pub usdc_amount: u64,
+    pub bond_amount: u64,
+    pub interest_rate: i16,
+    pub is_interest_bearing: bool,
+    pub bond_issuance_number: u64,
+    pub payment_feed_type: u8,
+    pub oft_store: Option<Pubkey>,
+    pub is_cross_chain_enabled: bool,
+    pub cross_chain_admin: Option<Pubkey>,
+}
+
+impl LegacySovereignCoin {
+    /// The coin in the current layout. It stays active and keeps paying yield the way it did before:
+    /// through the mint's rate when interest-bearing, otherwise by leaving it in the bonds.
+    pub fn into_current(self) -> SovereignCoin {
+        SovereignCoin {
+            bump: self.bump,
+            creator: self.creator,
+            factory: self.factory,
+            name: self.name,
+            symbol: self.symbol,
+            decimals: self.decimals,
+            uri: self.uri,
+            target_fiat_currency: self.target_fiat_currency,
+            bond_mint: self.bond_mint,
+            bond_account: self.bond_account,
+            mint: self.mint,
+            bond_holding: self.bond_holding,
+            bond_ownership: self.bond_ownership,
+            total_supply: self.total_supply,
+            bond_rating: self.bond_rating,
+            required_reserve_percentage: self.required_reserve_percentage,
+            usdc_amount: self.usdc_amount,
+            bond_amount: self.bond_amount,
+            interest_rate: self.interest_rate,
+            is_interest_bearing: self.is_interest_bearing,
+            bond_issuance_number: self.bond_issuance_number,
+            payment_feed_type: self.payment_feed_type,
+            oft_store: self.oft_store,
+            is_cross_chain_enabled: self.is_cross_chain_enabled,
+            cross_chain_admin: self.cross_chain_admin,
+            uses_token_metadata: false,
+            lifecycle: SovereignCoinLifecycle::Active,
+            settled_at: 0,
+            is_basket: false,
+            net_yield_rate: 0,
+            protocol_yield_accrued: 0,
+            issuer_yield_accrued: 0,
+            yield_accrued_at: 0,
+            yield_mode: if self.is_interest_bearing {
+                YieldMode::InterestBearing
+            } else {
+                YieldMode::Reinvest
+            },
+            reward_payout: RewardPayout::Usdc,
+            reward_index: 0,
+            reward_deposits: 0,
+            holder_yield_accrued: 0,
+            reward_usdc_balance: 0,
+            marked_bond_value: 0,
+            collateral_ratio_bps: 0,
+            collateral_marked_at: 0,
+            mint_spread_bps: 0,
+            redeem_spread_bps: 0,
+            spread_recipient: SpreadRecipient::Protocol,
+            spread_revenue_accrued: 0,
+            currency_stats_tracked: false,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SpreadRecipient {
+    Protocol, // Paid to the protocol vault
+    Issuer,   // Paid to the issuer's USDC account
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum YieldMode {
+    InterestBearing, // Holders' share compounds into the Token-2022 interest-bearing rate
+    Claimable,       // Holders deposit coins and claim their share through a reward index
+    Reinvest,        // Holders' share stays in bonds and raises the coin's backing
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RewardPayout {
+    Usdc,          // Paid out of the reserve
+    SovereignCoin, // Minted at the coin's current backing, the USDC stays in the reserve behind it
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SovereignCoinLifecycle {
+    Active,      // Minting, redemptions and cross-chain sends are all open
+    RedeemOnly,  // No new supply, holders can still redeem through the normal waterfall
+    Settled,     // All bonds redeemed into USDC, holders claim their pro-rata share
+    Closed,      // Supply fully claimed, coin accounts closed
 }
 
 impl SovereignCoin {
+    /// Minting and cross-chain sends are only allowed while the coin is active
+    pub fn is_active(&self) -> bool {
+        self.lifecycle == SovereignCoinLifecycle::Active
+    }
+
+    /// Normal redemptions stay open until the coin is settled
+    pub fn accepts_redemptions(&self) -> bool {
+        matches!(
+            self.lifecycle,
+            SovereignCoinLifecycle::Active | SovereignCoinLifecycle::RedeemOnly
+        )
+    }
+
+    /// Target currency code without the zero padding
+    pub fn target_currency(&self) -> Result<&str> {
+        let len = self.target_fiat_currency.iter().position(|&x| x == 0).unwrap_or(8);
+        std::str::from_utf8(&self.target_fiat_currency[..len])
+            .map_err(|_| error!(StablecoinError::InvalidFiatCurrency))
+    }
+
+    /// Name, symbol and uri without the zero padding, checked against the metadata length limits
+    pub fn metadata_strings(&self) -> Result<(String, String, String)> {
+        let decode = |bytes: &[u8]| -> String {
+            let len = bytes.iter().position(|&x| x == 0).unwrap_or(bytes.len());
+            std::str::from_utf8(&bytes[..len]).unwrap_or("").to_string()
+        };
+        let name = decode(&self.name);
+        let symbol = decode(&self.symbol);
+        let uri = decode(&self.uri);
+
+        require!(
+            !name.is_empty() && name.len() <= 32,
+            StablecoinError::InvalidNameLength
+        );
+        require!(
+            !symbol.is_empty() && symbol.len() <= 8,
+            StablecoinError::InvalidSymbolLength
+        );
+        require!(
+            uri.len() <= 200,
+            StablecoinError::InvalidUriLength
+        );
+
+        Ok((name, symbol, uri))
+    }
+
+    /// Only the issuer or a protocol admin can move the coin through its wind-down
+    pub fn is_lifecycle_authority(&self, user: &Pubkey) -> bool {
+        *user == self.creator || is_admin(user)
+    }
+
+    /// Book the protocol and issuer shares of the yield earned on the bonds since the last accrual.
+    /// Must run before `net_yield_rate` or `bond_amount` change so each period uses its own rate.
+    pub fn accrue_yield_claims(&mut self, factory: &Factory, unix_timestamp: i64) -> Result<()> {
+        if self.yield_accrued_at > 0 && unix_timestamp > self.yield_accrued_at {
+            let elapsed = unix_timestamp.safe_sub(self.yield_accrued_at)?;
+            let yield_earned = interest::calculate_accrued_yield(self.bond_amount, self.net_yield_rate, elapsed)?;
+
+            self.protocol_yield_accrued = self.protocol_yield_accrued
+                .safe_add(calculate_percentage(yield_earned, factory.yield_share_protocol, Rounding::Down)?)?;
+            self.issuer_yield_accrued = self.issuer_yield_accrued
+                .safe_add(calculate_percentage(yield_earned, factory.yield_share_issuer, Rounding::Down)?)?;
+
+            let holder_yield = calculate_percentage(yield_earned, factory.yield_share_holders, Rounding::Down)?;
+            match self.yield_mode {
+                // Already paid through the mint's rate
+                YieldMode::InterestBearing => {}
+                YieldMode::Claimable if self.reward_deposits > 0 => {
+                    self.holder_yield_accrued = self.holder_yield_accrued.safe_add(holder_yield)?;
+                }
+                // With nobody deposited a claimable coin's yield has no one to go to, so it backs the coin instead
+                YieldMode::Claimable | YieldMode::Reinvest => {
+                    self.bond_amount = self.bond_amount.safe_add(holder_yield)?;
+                }
+            }
+        }
+        self.yield_accrued_at = unix_timestamp;
+
+        Ok(())
+    }
+
+    /// Protocol, issuer and holder reward yield booked against the bond holding and not yet settled, in USDC
+    pub fn unsettled_yield_claims(&self) -> Result<u64> {
+        self.protocol_yield_accrued
+            .safe_add(self.issuer_yield_accrued)?
+            .safe_add(self.holder_yield_accrued)
+    }
+
+    /// Keep the latest mark to market on the coin for indexers and health monitoring
+    pub fn record_collateral_mark(&mut self, mark: &CollateralMark, unix_timestamp: i64) {
+        self.marked_bond_value = mark.bond_value;
+        self.collateral_ratio_bps = mark.collateral_ratio_bps;
+        self.collateral_marked_at = unix_timestamp;
+    }
+
+    /// Move a redemption's spread out of the coin's backing and into the recipient's accrued revenue.
+    /// Capped by what is left in the reserve, anything short stays behind as backing for holders.
+    pub fn book_redeem_spread(&mut self, spread_amount: u64) -> Result<u64> {
+        let booked = spread_amount.min(self.usdc_amount);
+        self.usdc_amount = self.usdc_amount.safe_sub(booked)?;
+        self.spread_revenue_accrued = self.spread_revenue_accrued.safe_add(booked)?;
+
+        Ok(booked)
+    }
+
+    /// Rate the mint should carry, only interest-bearing coins pass yield through the token itself
+    pub fn applied_interest_rate(&self, net_rate: i16, yield_share_holders: u16) -> Result<i16> {
+        match self.yield_mode {
+            YieldMode::InterestBearing => interest::apply_holder_yield_share(net_rate, yield_share_holders),
+            YieldMode::Claimable | YieldMode::Reinvest => Ok(0),
+        }
+    }
+
+    /// Spread USDC that just landed in the reserve across the deposited coins
+    pub fn distribute_rewards(&mut self, usdc_amount: u64) -> Result<()> {
+        if self.reward_deposits == 0 {
+            // Everyone withdrew before the yield was realized, it backs the coin instead
+            self.usdc_amount = self.usdc_amount.safe_add(usdc_amount)?;
+            return Ok(());
+        }
+
+        let index_increase = (usdc_amount as u128)
+            .safe_mul(REWARD_INDEX_PRECISION)?
+            .safe_div(self.reward_deposits as u128)?;
+        self.reward_index = self.reward_index.safe_add(index_increase)?;
+        self.reward_usdc_balance = self.reward_usdc_balance.safe_add(usdc_amount)?;
+
+        Ok(())
+    }
+
     /// Convert stored u8 back to PaymentFeedType
     pub fn get_payment_feed_type(&self) -> Result<PaymentFeedType> {
-        match self.payment_feed_type {
-            0 => Ok(PaymentFeedType::UsdcUsd),
-            1 => Ok(PaymentFeedType::UsdcMxn),
-            2 => Ok(PaymentFeedType::SwitchboardUsdcUsd),
-            3 => Ok(PaymentFeedType::SwitchboardUsdcMxn),
-            4 => Ok(PaymentFeedType::SwitchboardUsdcBrl),
-            5 => Ok(PaymentFeedType::SwitchboardUsdcGbp),
-            6 => Ok(PaymentFeedType::SwitchboardUsdcEur),
-            7 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcUsd),
-            8 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcMxn),
-            9 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcBrl),
-            10 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcGbp),
-            11 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcEur),
-            12 => Ok(PaymentFeedType::Stub),
-            _ => Err(StablecoinError::InvalidPriceFeed.into()),
-        }
+        Self::u8_to_payment_feed_type(self.payment_feed_type)
     }
     
     /// Set PaymentFeedType from the enum - handles all known variants
     pub fn set_payment_feed_type(&mut self, feed_type: PaymentFeedType) -> Result<()> {
-        self.payment_feed_type = match feed_type {
+        self.payment_feed_type = Self::payment_feed_type_to_u8(feed_type);
+        Ok(())
+    }
+
+    /// Stored form of a payment feed type, shared with the basket constituents
+    pub fn payment_feed_type_to_u8(feed_type: PaymentFeedType) -> u8 {
+        match feed_type {
             PaymentFeedType::UsdcUsd => 0,
             PaymentFeedType::UsdcMxn => 1,
             PaymentFeedType::SwitchboardUsdcUsd => 2,
@@ -72,19 +334,26 @@ impl SovereignCoin {
 
             // If there are other variants, add them here
             // You can check the stablebond-sdk docs or source code for complete list
-        };
-        Ok(())
+        }
     }
 
+    /// Inverse of `payment_feed_type_to_u8`
     pub fn u8_to_payment_feed_type(value: u8) -> Result<PaymentFeedType> {
-    match value {
-        0 => Ok(PaymentFeedType::UsdcUsd),
-        1 => Ok(PaymentFeedType::UsdcMxn),
-        2 => Ok(PaymentFeedType::SwitchboardUsdcUsd),
-        3 => Ok(PaymentFeedType::SwitchboardUsdcMxn),
-        4 => Ok(PaymentFeedType::Stub),
-        _ => Err(StablecoinError::InvalidPriceFeed.into()),
+        match value {
+            0 => Ok(PaymentFeedType::UsdcUsd),
+            1 => Ok(PaymentFeedType::UsdcMxn),
+            2 => Ok(PaymentFeedType::SwitchboardUsdcUsd),
+            3 => Ok(PaymentFeedType::SwitchboardUsdcMxn),
+            4 => Ok(PaymentFeedType::SwitchboardUsdcBrl),
+            5 => Ok(PaymentFeedType::SwitchboardUsdcGbp),
+            6 => Ok(PaymentFeedType::SwitchboardUsdcEur),
+            7 => Ok(PaymentFeedType::Stub),
+            8 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcUsd),
+            9 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcMxn),
+            10 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcBrl),
+            11 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcGbp),
+            12 => Ok(PaymentFeedType::SwitchboardOnDemandUsdcEur),
+            _ => Err(StablecoinError::InvalidPriceFeed.into()),
+        }
     }
 }
-
-}
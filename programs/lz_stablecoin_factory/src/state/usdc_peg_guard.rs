@@ -0,0 +1,62 @@
+use super::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DepegAction {
+    Pause,         // USD coin mints and redemptions stop until USDC is back in the band
+    OraclePriced,  // USD coins convert at the USDC/USD price instead of one-to-one
+}
+
+/// Circuit breaker on the base USDC/USD feed for USD coins
+#[account]
+#[derive(InitSpace)]
+pub struct UsdcPegGuard {
+    pub bump: u8,
+    pub band_bps: u16,          // Allowed distance of USDC/USD from 1.0
+    pub action: DepegAction,
+    pub is_depegged: bool,      // Tripped until a check sees USDC back inside the band
+    pub last_price: u64,        // USDC/USD scaled by PRICE_PRECISION
+    pub last_checked_at: i64,
+    pub depegged_since: i64,
+    pub updated_at: i64,
+}
+
+impl UsdcPegGuard {
+    pub fn is_within_band(&self, usdc_price: u64) -> Result<bool> {
+        let deviation = usdc_price.abs_diff(PRICE_PRECISION);
+        let max_deviation = calculate_percentage(PRICE_PRECISION, self.band_bps, Rounding::Down)?;
+
+        Ok(deviation <= max_deviation)
+    }
+
+    /// Record a fresh USDC/USD reading, returns true when the peg status flipped
+    pub fn record_price(&mut self, usdc_price: u64, unix_timestamp: i64) -> Result<bool> {
+        let was_depegged = self.is_depegged;
+        self.is_depegged = !self.is_within_band(usdc_price)?;
+        self.last_price = usdc_price;
+        self.last_checked_at = unix_timestamp;
+        if self.is_depegged && !was_depegged {
+            self.depegged_since = unix_timestamp;
+        }
+
+        Ok(self.is_depegged != was_depegged)
+    }
+
+    /// Price a USD coin trade should use after the latest reading: None while USDC holds its peg,
+    /// the oracle price when depegged in OraclePriced mode. Fails while depegged in Pause mode.
+    pub fn usd_conversion_price(&self) -> Result<Option<u64>> {
+        if !self.is_depegged {
+            return Ok(None);
+        }
+        require!(self.action == DepegAction::OraclePriced, StablecoinError::UsdcDepegged);
+
+        Ok(Some(self.last_price))
+    }
+
+    /// What `usd_conversion_price` would return after recording `usdc_price`, leaving the guard untouched
+    pub fn preview_conversion_price(&self, usdc_price: u64) -> Result<Option<u64>> {
+        let mut guard = self.clone();
+        guard.record_price(usdc_price, self.last_checked_at)?;
+
+        guard.usd_conversion_price()
+    }
+}